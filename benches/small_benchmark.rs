@@ -214,10 +214,55 @@ pub fn sibling_benchmark(c: &mut Criterion) {
     });
 }
 
+pub fn large_synthetic_benchmark(c: &mut Criterion) {
+    let mut input = String::from(ONE_NODE.trim_end_matches("0 TRLR"));
+    for i in 2..=5_000 {
+        input.push_str(&format!(
+            "0 @I{}@ INDI\n1 NAME Person{} /Synthetic/\n1 SEX F\n1 BIRT\n2 _PRIM Y\n2 DATE 1 Jan 1900\n2 PLAC Dundee\n1 CHAN\n2 DATE 15 APR 2020\n3 TIME 16:19:21\n",
+            i, i
+        ));
+    }
+    input.push_str("0 TRLR");
+
+    c.bench_function("gedcom_to_relation_json LARGE_SYNTHETIC", |b| {
+        b.iter(|| gedcom_to_relation_json(black_box(&input)))
+    });
+}
+
+pub fn large_synthetic_families_benchmark(c: &mut Criterion) {
+    let mut input = String::from(ONE_NODE.trim_end_matches("0 TRLR"));
+    for i in 1..=1_250 {
+        let (father, mother, child) = (i * 4 + 1, i * 4 + 2, i * 4 + 3);
+        for (id, sex, link) in [
+            (father, "M", "FAMS"),
+            (mother, "F", "FAMS"),
+            (child, "F", "FAMC"),
+        ]
+        .iter()
+        {
+            input.push_str(&format!(
+                "0 @I{}@ INDI\n1 NAME Person{} /Synthetic/\n1 SEX {}\n1 BIRT\n2 _PRIM Y\n2 DATE 1 Jan 1900\n2 PLAC Dundee\n1 {} @F{}@\n1 CHAN\n2 DATE 15 APR 2020\n3 TIME 16:19:21\n",
+                id, id, sex, link, i
+            ));
+        }
+        input.push_str(&format!(
+            "0 @F{}@ FAM\n1 HUSB @I{}@\n1 WIFE @I{}@\n1 CHIL @I{}@\n1 MARR\n2 DATE 1 Jan 1920\n1 CHAN\n2 DATE 15 APR 2020\n3 TIME 16:19:21\n",
+            i, father, mother, child
+        ));
+    }
+    input.push_str("0 TRLR");
+
+    c.bench_function("gedcom_to_relation_json LARGE_SYNTHETIC_FAMILIES", |b| {
+        b.iter(|| gedcom_to_relation_json(black_box(&input)))
+    });
+}
+
 criterion_group!(
     benches,
     one_node_benchmark,
     three_node_benchmark,
-    sibling_benchmark
+    sibling_benchmark,
+    large_synthetic_benchmark,
+    large_synthetic_families_benchmark
 );
 criterion_main!(benches);
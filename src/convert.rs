@@ -0,0 +1,1456 @@
+use crate::{
+    models::{
+        gedcom::{GedcomLine, GedcomTree, ValidationWarning},
+        relation::{ApiResponse, ConversionOptions},
+    },
+    parser::{
+        decode_gedcom_bytes, parse_gedcom_complete, parse_gedcom_lenient_numbered, ParseError,
+    },
+};
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::Serialize;
+use serde_json::{self, Value};
+#[cfg(feature = "gzip")]
+use std::io::Read;
+use std::{
+    error::Error,
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+pub const DATE_CREATED_FORMAT: &str = "%Y-%m-%dT%H:%M:%S";
+
+const CSV: &str = "csv";
+const FAMILIES_CSV_HEADER: [&str; 3] = ["id", "father_id", "mother_id"];
+const GZIP: &str = "gz";
+const JSON: &str = "json";
+const NDJSON: &str = "ndjson";
+const PERSONS_CSV_HEADER: [&str; 7] = [
+    "id",
+    "given_names",
+    "surnames",
+    "gender",
+    "birth_date",
+    "birth_place",
+    "is_living",
+];
+
+type NumberedLines = (Vec<(usize, GedcomLine)>, Vec<ParseError>);
+
+lazy_static! {
+    static ref XREF_ID_DIGITS: Regex = Regex::new(r#"@.+([0-9]+)@"#).unwrap();
+}
+
+pub fn gedcom_to_relation_json(input: &str) -> Result<String, Box<dyn Error>> {
+    let json = gedcom_to_relation_value(input)?.to_string();
+    Ok(json)
+}
+
+pub fn gedcom_bytes_to_relation_json(input: &[u8]) -> Result<String, Box<dyn Error>> {
+    let gedcom = decode_gedcom_bytes(input, None)?;
+    gedcom_to_relation_json(&gedcom)
+}
+
+pub fn write_relation_json<W: Write>(input: &str, writer: W) -> Result<(), Box<dyn Error>> {
+    let api_response = gedcom_to_api_response(input)?;
+    write_api_response_json(&api_response, writer)
+}
+
+pub fn gedcom_to_relation_json_with_options(
+    input: &str,
+    options: &ConversionOptions,
+) -> Result<String, Box<dyn Error>> {
+    let api_response = gedcom_to_api_response_with_options(input, options)?;
+    Ok(api_response_to_json(&api_response))
+}
+
+pub fn gedcom_to_relation_json_pretty(input: &str) -> Result<String, Box<dyn Error>> {
+    let api_response = gedcom_to_api_response(input)?;
+    let json = api_response_to_json_pretty(&api_response)?;
+    Ok(json)
+}
+
+pub fn gedcom_to_relation_value(input: &str) -> Result<serde_json::Value, Box<dyn Error>> {
+    let api_response = gedcom_to_api_response(input)?;
+    let value = serde_json::json!(api_response);
+    Ok(value)
+}
+
+pub fn gedcom_to_api_response(input: &str) -> Result<ApiResponse, Box<dyn Error>> {
+    gedcom_to_api_response_with_options(input, &ConversionOptions::default())
+}
+
+pub fn gedcom_to_api_response_with_options(
+    input: &str,
+    options: &ConversionOptions,
+) -> Result<ApiResponse, Box<dyn Error>> {
+    let (gedcom_lines, parse_errors) = parse_gedcom_numbered(input, options.strict())?;
+    let raw_lines = if options.include_raw_lines() {
+        gedcom_lines.iter().map(|(_, line)| line.clone()).collect()
+    } else {
+        vec![]
+    };
+
+    let mut api_response = ApiResponse::from_tree(GedcomTree::from(gedcom_lines), options);
+    api_response.with_raw_lines(raw_lines);
+
+    for parse_error in parse_errors.iter() {
+        api_response.with_warning(parse_error.to_string());
+    }
+
+    if options.strict() {
+        if let Some(version) = api_response.header().and_then(|h| h.unsupported_version()) {
+            return Err(format!("Unsupported GEDCOM version {}", version).into());
+        }
+    }
+
+    Ok(api_response)
+}
+
+pub fn validate_gedcom(input: &str) -> Result<Vec<ValidationWarning>, Box<dyn Error>> {
+    let (gedcom_lines, parse_errors) = parse_gedcom_numbered(input, false)?;
+    let mut warnings: Vec<ValidationWarning> =
+        parse_errors.iter().map(ValidationWarning::from).collect();
+
+    warnings.extend(GedcomTree::from(gedcom_lines).validate());
+    warnings.sort_by_key(|w| w.line());
+    Ok(warnings)
+}
+
+fn parse_gedcom_numbered(input: &str, strict: bool) -> Result<NumberedLines, Box<dyn Error>> {
+    if strict {
+        let gedcom_lines = parse_gedcom_complete(input)?
+            .into_iter()
+            .enumerate()
+            .map(|(index, line)| (index + 1, line))
+            .collect();
+
+        return Ok((gedcom_lines, vec![]));
+    }
+
+    let (gedcom_lines, parse_errors) = parse_gedcom_lenient_numbered(input);
+
+    if gedcom_lines.is_empty() {
+        let message = match parse_errors.first() {
+            Some(parse_error) => format!("Could not parse GEDCOM input: {}", parse_error),
+            None => String::from("Could not parse GEDCOM input: no lines found"),
+        };
+        return Err(message.into());
+    }
+
+    Ok((gedcom_lines, parse_errors))
+}
+
+#[cfg(feature = "gzip")]
+pub fn decompress_gzip(input: &[u8]) -> Result<String, Box<dyn Error>> {
+    let mut decoder = flate2::read::GzDecoder::new(input);
+    let mut bytes = vec![];
+    decoder.read_to_end(&mut bytes)?;
+    decode_gedcom_bytes(&bytes, None)
+}
+
+#[cfg(feature = "gzip")]
+fn read_gzip(input_path: &Path) -> Result<String, Box<dyn Error>> {
+    let bytes = fs::read(input_path)?;
+    decompress_gzip(&bytes)
+}
+
+#[cfg(not(feature = "gzip"))]
+fn read_gzip(input_path: &Path) -> Result<String, Box<dyn Error>> {
+    let message = format!(
+        "Cannot read {}, gzip support requires the gzip feature",
+        input_path.display()
+    );
+    Err(message.into())
+}
+
+pub fn read_gedcom_file(input_path: &Path) -> Result<String, Box<dyn Error>> {
+    if input_path.extension().unwrap_or_default() == GZIP {
+        return read_gzip(input_path);
+    }
+
+    let bytes = fs::read(input_path)?;
+    decode_gedcom_bytes(&bytes, None)
+}
+
+pub fn is_gedcom_path(input_path: &Path) -> bool {
+    let extension = input_path.extension().unwrap_or_default();
+    input_path.is_file() && extension != JSON && extension != CSV && extension != NDJSON
+}
+
+pub fn output_path(input_path: &Path, extension: &str) -> PathBuf {
+    if input_path.extension().unwrap_or_default() == GZIP {
+        input_path.with_extension("").with_extension(extension)
+    } else {
+        input_path.with_extension(extension)
+    }
+}
+
+pub fn json_output_path(input_path: &Path) -> PathBuf {
+    output_path(input_path, JSON)
+}
+
+#[cfg(feature = "async")]
+pub async fn convert_directory(
+    path: &Path,
+) -> Result<Vec<(PathBuf, String)>, Box<dyn Error + Send + Sync>> {
+    let mut input_paths = vec![];
+    let mut dir_entries = tokio::fs::read_dir(path).await?;
+
+    while let Some(entry) = dir_entries.next_entry().await? {
+        let input_path = entry.path();
+        if is_gedcom_path(&input_path) {
+            input_paths.push(input_path);
+        }
+    }
+
+    input_paths.sort();
+
+    let handles: Vec<_> = input_paths
+        .into_iter()
+        .map(|input_path| {
+            tokio::task::spawn_blocking(move || {
+                let json = read_gedcom_file(&input_path)
+                    .and_then(|gedcom| gedcom_to_relation_json(&gedcom))
+                    .map_err(|e| format!("{}: {}", input_path.display(), e))?;
+                Ok::<_, String>((input_path, json))
+            })
+        })
+        .collect();
+
+    let mut converted = vec![];
+    for handle in handles.into_iter() {
+        converted.push(handle.await??);
+    }
+
+    Ok(converted)
+}
+
+pub fn api_response_to_json(api_response: &ApiResponse) -> String {
+    serde_json::json!(api_response).to_string()
+}
+
+pub fn write_api_response_json<W: Write>(
+    api_response: &ApiResponse,
+    writer: W,
+) -> Result<(), Box<dyn Error>> {
+    serde_json::to_writer(writer, api_response)?;
+    Ok(())
+}
+
+pub fn write_api_response_json_pretty<W: Write>(
+    api_response: &ApiResponse,
+    writer: W,
+) -> Result<(), Box<dyn Error>> {
+    serde_json::to_writer_pretty(writer, api_response)?;
+    Ok(())
+}
+
+pub fn write_api_response_ndjson<W: Write>(
+    api_response: &ApiResponse,
+    mut writer: W,
+) -> Result<(), Box<dyn Error>> {
+    for person in api_response.persons().iter() {
+        write_ndjson_record(&mut writer, "Person", person)?;
+    }
+
+    for family in api_response.familys().iter() {
+        write_ndjson_record(&mut writer, "Family", family)?;
+    }
+
+    for child in api_response.childs().iter() {
+        write_ndjson_record(&mut writer, "Child", child)?;
+    }
+
+    Ok(())
+}
+
+fn write_ndjson_record<W: Write, T: Serialize>(
+    writer: &mut W,
+    record_type: &str,
+    record: &T,
+) -> Result<(), Box<dyn Error>> {
+    let mut value = serde_json::to_value(record)?;
+    if let Value::Object(map) = &mut value {
+        map.insert(String::from("Type"), Value::from(record_type));
+    }
+
+    serde_json::to_writer(&mut *writer, &value)?;
+    writer.write_all(b"\n")?;
+    Ok(())
+}
+
+#[cfg(feature = "schemars")]
+pub fn relation_json_schema() -> serde_json::Value {
+    serde_json::json!(schemars::schema_for!(ApiResponse))
+}
+
+pub fn api_response_to_persons_csv(api_response: &ApiResponse) -> String {
+    let mut csv = csv_row(&PERSONS_CSV_HEADER);
+
+    for person in api_response.persons().iter() {
+        let name = person.names().first();
+        let birth = person.birth();
+
+        let row = [
+            person.id().to_string(),
+            name.and_then(|n| n.given_names())
+                .unwrap_or_default()
+                .to_owned(),
+            name.and_then(|n| n.surnames())
+                .unwrap_or_default()
+                .to_owned(),
+            person.gender().to_string(),
+            birth
+                .and_then(|b| b.date_detail())
+                .map(|d| d.to_string())
+                .unwrap_or_default(),
+            birth
+                .and_then(|b| b.place())
+                .map(|p| p.place_name().to_owned())
+                .unwrap_or_default(),
+            person
+                .is_living()
+                .map(|l| l.to_string())
+                .unwrap_or_default(),
+        ];
+
+        csv.push_str(&csv_row(&row));
+    }
+
+    csv
+}
+
+pub fn api_response_to_facts_json(api_response: &ApiResponse) -> String {
+    serde_json::json!(api_response.person_facts()).to_string()
+}
+
+pub fn api_response_to_families_csv(api_response: &ApiResponse) -> String {
+    let mut csv = csv_row(&FAMILIES_CSV_HEADER);
+
+    for family in api_response.familys().iter() {
+        let row = [
+            family.id().to_string(),
+            family.father_id().to_string(),
+            family.mother_id().to_string(),
+        ];
+
+        csv.push_str(&csv_row(&row));
+    }
+
+    csv
+}
+
+fn csv_row<T: AsRef<str>>(fields: &[T]) -> String {
+    let fields: Vec<String> = fields
+        .iter()
+        .map(|field| {
+            let field = field.as_ref();
+            if field.contains(&[',', '"', '\r', '\n'][..]) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field.to_owned()
+            }
+        })
+        .collect();
+
+    format!("{}\r\n", fields.join(","))
+}
+
+pub fn api_response_to_json_pretty(api_response: &ApiResponse) -> Result<String, Box<dyn Error>> {
+    let json = serde_json::to_string_pretty(&serde_json::json!(api_response))?;
+    Ok(json)
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "schemars")]
+    use super::relation_json_schema;
+    use super::{
+        api_response_to_facts_json, api_response_to_json, api_response_to_persons_csv, csv_row,
+        gedcom_bytes_to_relation_json, gedcom_to_api_response, gedcom_to_api_response_with_options,
+        gedcom_to_relation_json, gedcom_to_relation_json_pretty,
+        gedcom_to_relation_json_with_options, gedcom_to_relation_value, is_gedcom_path,
+        read_gedcom_file, write_api_response_json_pretty, write_api_response_ndjson,
+        write_relation_json,
+    };
+    use crate::{
+        load_fixture, load_fixture_bytes,
+        models::relation::{
+            ApiResponse, ChildRelationship, ConversionOptions, Fact, FactMapper, FactTypeId,
+            MonthLocale,
+        },
+    };
+    use std::path::Path;
+
+    #[test]
+    fn one_node_gedcom_test() {
+        let input = load_fixture("one_node");
+
+        let expected = String::from(
+            r#"{"Childs":[],"FactTypes":[],"Familys":[],"MasterSources":[],"Medias":[],"Persons":[{"DateCreated":"2020-04-15T16:19:21","Facts":[{"DateDetail":"1 Jan 1990","FactTypeId":405,"Place":{"PlaceName":"Dundee"},"Preferred":true}],"Gender":1,"Id":1,"IsLiving":true,"Names":[{"FactTypeId":100,"GivenNames":"Gavin","Surnames":"Henderson"}]}],"SourceRepos":[]}"#,
+        );
+
+        let actual = gedcom_to_relation_json(&input);
+        assert!(actual.is_ok());
+
+        let actual = actual.unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn three_node_gedcom_test() {
+        let input = load_fixture("three_node");
+
+        let expected = String::from(
+            r#"{"Childs":[{"ChildId":1,"FamilyId":10000001,"Id":20000001,"RelationshipToFather":1,"RelationshipToMother":1}],"FactTypes":[],"Familys":[{"DateCreated":"2020-04-15T16:40:57","FatherId":3,"Id":10000001,"MotherId":2}],"MasterSources":[],"Medias":[],"Persons":[{"DateCreated":"2020-04-15T16:38:50","Facts":[{"DateDetail":"1 Jan 1990","FactTypeId":405,"Preferred":true}],"Gender":1,"Id":1,"IsLiving":true,"Names":[{"FactTypeId":100,"GivenNames":"Gavin","Surnames":"Henderson"}]},{"DateCreated":"2020-04-15T16:39:15","Facts":[{"FactTypeId":405,"Place":{"PlaceName":"Dundee"},"Preferred":true}],"Gender":2,"Id":2,"IsLiving":true,"Names":[{"FactTypeId":100,"GivenNames":"Jane","Surnames":"Reed"}]},{"DateCreated":"2020-04-15T16:41:07","Gender":1,"Id":3,"IsLiving":true,"Names":[{"FactTypeId":100,"GivenNames":"Frank","Surnames":"Henderson"}]}],"SourceRepos":[]}"#,
+        );
+
+        let actual = gedcom_to_relation_json(&input);
+        assert!(actual.is_ok());
+
+        let actual = actual.unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn sibling_gedcom_test() {
+        let input = load_fixture("sibling");
+
+        let expected = String::from(
+            r#"{"Childs":[{"ChildId":1,"FamilyId":10000001,"Id":20000001,"RelationshipToFather":1,"RelationshipToMother":1},{"ChildId":4,"FamilyId":10000001,"Id":20000002,"RelationshipToFather":1,"RelationshipToMother":1}],"FactTypes":[],"Familys":[{"DateCreated":"2020-04-15T16:43:01","FatherId":3,"Id":10000001,"MotherId":2}],"MasterSources":[],"Medias":[],"Persons":[{"DateCreated":"2020-04-15T16:42:39","Facts":[{"DateDetail":"1 Jan 1990","FactTypeId":405,"Preferred":true}],"Gender":1,"Id":1,"IsLiving":true,"Names":[{"FactTypeId":100,"GivenNames":"Gavin","Surnames":"Henderson"}]},{"DateCreated":"2020-04-15T16:43:06","Facts":[{"FactTypeId":405,"Preferred":true}],"Gender":2,"Id":2,"IsLiving":true,"Names":[{"FactTypeId":100,"GivenNames":"Jane","Surnames":"Smith"}]},{"DateCreated":"2020-04-15T16:43:01","Facts":[{"FactTypeId":405,"Preferred":true}],"Gender":1,"Id":3,"IsLiving":true,"Names":[{"FactTypeId":100,"GivenNames":"Frank","Surnames":"Henderson"}]},{"DateCreated":"2020-04-15T16:44:00","Gender":1,"Id":4,"IsLiving":true,"Names":[{"FactTypeId":100,"GivenNames":"Rachel","Surnames":"Henderson"}]}],"SourceRepos":[]}"#,
+        );
+
+        let actual = gedcom_to_relation_json(&input);
+        assert!(actual.is_ok());
+
+        let actual = actual.unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn pretty_gedcom_test() {
+        let input = load_fixture("pretty");
+
+        let minified = gedcom_to_relation_json(&input).unwrap();
+        let pretty = gedcom_to_relation_json_pretty(&input);
+        assert!(pretty.is_ok());
+
+        let pretty = pretty.unwrap();
+        assert!(pretty.contains('\n'));
+        assert_ne!(pretty, minified);
+
+        let expected: ApiResponse = serde_json::from_str(&minified).unwrap();
+        let actual: ApiResponse = serde_json::from_str(&pretty).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn change_without_time_gedcom_test() {
+        let input = load_fixture("change_without_time");
+
+        let expected = String::from(
+            r#"{"Childs":[],"FactTypes":[],"Familys":[],"MasterSources":[],"Medias":[],"Persons":[{"DateCreated":"2020-04-15T00:00:00","Gender":1,"Id":1,"IsLiving":true,"Names":[{"FactTypeId":100,"GivenNames":"Gavin","Surnames":"Henderson"}]}],"SourceRepos":[]}"#,
+        );
+
+        let actual = gedcom_to_relation_json(&input);
+        assert!(actual.is_ok());
+
+        let actual = actual.unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn restricted_gedcom_test() {
+        let input = load_fixture("restricted");
+
+        let expected = String::from(
+            r#"{"Childs":[],"FactTypes":[],"Familys":[],"MasterSources":[],"Medias":[],"Persons":[{"DateCreated":"2020-04-15T16:19:21","Facts":[{"DateDetail":"1 Jan 1990","FactTypeId":405,"Place":{"PlaceName":"Dundee"},"Preferred":true}],"Gender":1,"Id":1,"IsLiving":true,"Names":[{"FactTypeId":100,"GivenNames":"Gavin","Surnames":"Henderson"}],"ReferenceNumbers":[["1234","Census"]],"Restriction":"Confidential","Rin":"7"},{"DateCreated":"2020-04-15T16:39:15","Gender":2,"Id":2,"IsLiving":true,"Names":[{"FactTypeId":100,"GivenNames":"Jane","Surnames":"Reed"}],"Restriction":"Locked"}],"SourceRepos":[]}"#,
+        );
+
+        let mut api_response = gedcom_to_api_response(&input).unwrap();
+        let actual = api_response_to_json(&api_response);
+        assert_eq!(actual, expected);
+
+        let expected = String::from(
+            r#"{"Childs":[],"FactTypes":[],"Familys":[],"MasterSources":[],"Medias":[],"Persons":[{"DateCreated":"2020-04-15T16:19:21","Gender":3,"Id":1,"IsLiving":true,"Names":[{"FactTypeId":100,"GivenNames":"Private"}],"Restriction":"Confidential"},{"DateCreated":"2020-04-15T16:39:15","Gender":2,"Id":2,"IsLiving":true,"Names":[{"FactTypeId":100,"GivenNames":"Jane","Surnames":"Reed"}],"Restriction":"Locked"}],"SourceRepos":[]}"#,
+        );
+
+        api_response.redact_private();
+        let actual = api_response_to_json(&api_response);
+        assert_eq!(actual, expected);
+        assert!(api_response.persons()[0].reference_numbers().is_empty());
+        assert_eq!(api_response.persons()[0].rin(), None);
+    }
+
+    #[test]
+    fn value_gedcom_test() {
+        let input = load_fixture("value");
+
+        let actual = gedcom_to_relation_value(&input);
+        assert!(actual.is_ok());
+
+        let actual = actual.unwrap();
+        assert_eq!(actual["Persons"].as_array().map(|p| p.len()), Some(3));
+        assert_eq!(actual["Familys"].as_array().map(|f| f.len()), Some(1));
+        assert_eq!(actual.to_string(), gedcom_to_relation_json(&input).unwrap());
+    }
+
+    #[test]
+    fn stats_gedcom_test() {
+        let input = load_fixture("sibling");
+
+        let actual = gedcom_to_api_response(&input);
+        assert!(actual.is_ok());
+
+        let actual = actual.unwrap().stats();
+        assert_eq!(actual.persons(), 4);
+        assert_eq!(actual.families(), 1);
+        assert_eq!(actual.child_links(), 2);
+        assert_eq!(actual.generations(), 2);
+    }
+
+    #[test]
+    fn adoption_gedcom_test() {
+        let input = load_fixture("adoption");
+
+        let expected = String::from(
+            r#"{"Childs":[{"ChildId":1,"FamilyId":10000001,"Id":20000001,"RelationshipToFather":2,"RelationshipToMother":2}],"FactTypes":[],"Familys":[{"DateCreated":"2020-04-15T16:40:57","FatherId":3,"Id":10000001,"MotherId":2}],"MasterSources":[],"Medias":[],"Persons":[{"DateCreated":"2020-04-15T16:38:50","Facts":[{"AdoptedBy":"Both","FactTypeId":409}],"Gender":1,"Id":1,"IsLiving":true,"Names":[{"FactTypeId":100,"GivenNames":"Gavin","Surnames":"Henderson"}]},{"DateCreated":"2020-04-15T16:39:15","Gender":2,"Id":2,"IsLiving":true,"Names":[{"FactTypeId":100,"GivenNames":"Jane","Surnames":"Reed"}]},{"DateCreated":"2020-04-15T16:41:07","Gender":1,"Id":3,"IsLiving":true,"Names":[{"FactTypeId":100,"GivenNames":"Frank","Surnames":"Henderson"}]}],"SourceRepos":[]}"#,
+        );
+
+        let actual = gedcom_to_relation_json(&input);
+        assert!(actual.is_ok());
+
+        let actual = actual.unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn skip_empty_facts_gedcom_test() {
+        let input = load_fixture("sibling");
+
+        let expected = String::from(
+            r#"{"Childs":[{"ChildId":1,"FamilyId":10000001,"Id":20000001,"RelationshipToFather":1,"RelationshipToMother":1},{"ChildId":4,"FamilyId":10000001,"Id":20000002,"RelationshipToFather":1,"RelationshipToMother":1}],"FactTypes":[],"Familys":[{"DateCreated":"2020-04-15T16:43:01","FatherId":3,"Id":10000001,"MotherId":2}],"MasterSources":[],"Medias":[],"Persons":[{"DateCreated":"2020-04-15T16:42:39","Facts":[{"DateDetail":"1 Jan 1990","FactTypeId":405,"Preferred":true}],"Gender":1,"Id":1,"IsLiving":true,"Names":[{"FactTypeId":100,"GivenNames":"Gavin","Surnames":"Henderson"}]},{"DateCreated":"2020-04-15T16:43:06","Gender":2,"Id":2,"IsLiving":true,"Names":[{"FactTypeId":100,"GivenNames":"Jane","Surnames":"Smith"}]},{"DateCreated":"2020-04-15T16:43:01","Gender":1,"Id":3,"IsLiving":true,"Names":[{"FactTypeId":100,"GivenNames":"Frank","Surnames":"Henderson"}]},{"DateCreated":"2020-04-15T16:44:00","Gender":1,"Id":4,"IsLiving":true,"Names":[{"FactTypeId":100,"GivenNames":"Rachel","Surnames":"Henderson"}]}],"SourceRepos":[]}"#,
+        );
+
+        let options = ConversionOptions::builder().skip_empty_facts().build();
+        let actual = gedcom_to_api_response_with_options(&input, &options);
+        assert!(actual.is_ok());
+
+        let actual = api_response_to_json(&actual.unwrap());
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn skip_empty_facts_keeps_cited_facts_test() {
+        let input = "0 HEAD\n1 CHAR UTF-8\n0 @I1@ INDI\n1 NAME Jane /Reed/\n1 SEX F\n1 BIRT\n2 SOUR @S1@\n1 DEAT\n1 CHAN\n2 DATE 15 APR 2020\n0 TRLR\n";
+        let options = ConversionOptions::builder().skip_empty_facts().build();
+
+        let api_response = gedcom_to_api_response_with_options(input, &options).unwrap();
+        let facts = api_response.persons()[0].facts();
+
+        assert_eq!(facts.len(), 1);
+        assert_eq!(facts[0].fact_type_id(), FactTypeId::Birth);
+        assert_eq!(facts[0].citations()[0].source_id(), "S1");
+
+        let input = "0 HEAD\n1 CHAR UTF-8\n0 @I1@ INDI\n1 NAME Jane /Reed/\n1 SEX F\n1 EMIG\n2 AGE 42\n1 CHAN\n2 DATE 15 APR 2020\n0 TRLR\n";
+        let api_response = gedcom_to_api_response_with_options(input, &options).unwrap();
+        let facts = api_response.persons()[0].facts();
+
+        assert_eq!(facts.len(), 1);
+        assert_eq!(facts[0].fact_type_id(), FactTypeId::Emigration);
+
+        let input = "0 HEAD\n1 CHAR UTF-8\n0 @I1@ INDI\n1 NAME Jane /Reed/\n1 SEX F\n1 CHR\n2 QUAY 2\n1 CHAN\n2 DATE 15 APR 2020\n0 TRLR\n";
+        let api_response = gedcom_to_api_response_with_options(input, &options).unwrap();
+        let facts = api_response.persons()[0].facts();
+
+        assert_eq!(facts.len(), 1);
+        assert_eq!(facts[0].fact_type_id(), FactTypeId::Christening);
+    }
+
+    #[test]
+    fn place_form_gedcom_test() {
+        let input = load_fixture("place_form");
+
+        let expected = String::from(
+            r#"{"Childs":[],"FactTypes":[],"Familys":[],"MasterSources":[],"Medias":[],"Persons":[{"DateCreated":"2020-04-15T16:39:15","Facts":[{"FactTypeId":405,"Place":{"Components":[{"Label":"City","Value":"Dundee"},{"Label":"County","Value":"Angus"},{"Label":"Country","Value":"Scotland"}],"PlaceName":"Dundee, Angus, Scotland"},"Preferred":true}],"Gender":2,"Id":1,"IsLiving":true,"Names":[{"FactTypeId":100,"GivenNames":"Jane","Surnames":"Reed"}]}],"SourceRepos":[]}"#,
+        );
+
+        let actual = gedcom_to_relation_json(&input);
+        assert!(actual.is_ok());
+
+        let actual = actual.unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn title_gedcom_test() {
+        let input = load_fixture("title");
+
+        let expected = String::from(
+            r#"{"Childs":[],"FactTypes":[],"Familys":[],"MasterSources":[],"Medias":[],"Persons":[{"DateCreated":"2020-04-15T16:39:15","Gender":1,"Id":1,"IsLiving":true,"Names":[{"FactTypeId":100,"GivenNames":"Frederick","Surnames":"Windsor"}],"Title":"Duke of York"}],"SourceRepos":[]}"#,
+        );
+
+        let actual = gedcom_to_relation_json(&input);
+        assert!(actual.is_ok());
+
+        let actual = actual.unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn cycle_gedcom_test() {
+        let input = load_fixture("cycle");
+
+        let actual = gedcom_to_api_response(&input);
+        assert!(actual.is_ok());
+
+        let actual = actual.unwrap();
+        let expected = vec![String::from(
+            "Family 10000002 would make person 1 their own ancestor via persons [2, 1]",
+        )];
+        assert_eq!(actual.warnings(), expected.as_slice());
+
+        let json = api_response_to_json(&actual);
+        assert!(json.contains(r#""Childs":[{"ChildId":2,"FamilyId":10000001,"#));
+        assert!(!json.contains("Warnings"));
+    }
+
+    #[test]
+    fn submitter_gedcom_test() {
+        let input = load_fixture("one_node");
+
+        let actual = gedcom_to_api_response(&input);
+        assert!(actual.is_ok());
+
+        let actual = actual.unwrap();
+        let header = actual.header().unwrap();
+        assert_eq!(header.submitter_xref().unwrap().to_string(), "@SUBM1@");
+
+        let submitter = header.submitter().unwrap();
+        assert_eq!(submitter.name(), None);
+        assert_eq!(submitter.address(), None);
+    }
+
+    #[test]
+    fn id_bases_gedcom_test() {
+        let input = load_fixture("three_node");
+
+        let expected = String::from(
+            r#"{"Childs":[{"ChildId":101,"FamilyId":5001,"Id":9001,"RelationshipToFather":1,"RelationshipToMother":1}],"FactTypes":[],"Familys":[{"DateCreated":"2020-04-15T16:40:57","FatherId":105,"Id":5001,"MotherId":103}],"MasterSources":[],"Medias":[],"Persons":[{"DateCreated":"2020-04-15T16:38:50","Facts":[{"DateDetail":"1 Jan 1990","FactTypeId":405,"Preferred":true}],"Gender":1,"Id":101,"IsLiving":true,"Names":[{"FactTypeId":100,"GivenNames":"Gavin","Surnames":"Henderson"}]},{"DateCreated":"2020-04-15T16:39:15","Facts":[{"FactTypeId":405,"Place":{"PlaceName":"Dundee"},"Preferred":true}],"Gender":2,"Id":103,"IsLiving":true,"Names":[{"FactTypeId":100,"GivenNames":"Jane","Surnames":"Reed"}]},{"DateCreated":"2020-04-15T16:41:07","Gender":1,"Id":105,"IsLiving":true,"Names":[{"FactTypeId":100,"GivenNames":"Frank","Surnames":"Henderson"}]}],"SourceRepos":[]}"#,
+        );
+
+        let options = ConversionOptions::builder()
+            .with_person_id_base(101)
+            .with_family_id_base(5001)
+            .with_child_id_base(9001)
+            .with_id_step(2)
+            .build();
+        let actual = gedcom_to_relation_json_with_options(&input, &options);
+        assert!(actual.is_ok());
+
+        let actual = actual.unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn event_gedcom_test() {
+        let input = load_fixture("event");
+
+        let expected = String::from(
+            r#"{"Childs":[],"FactTypes":[],"Familys":[],"MasterSources":[],"Medias":[],"Persons":[{"DateCreated":"2020-04-15T16:38:50","Facts":[{"DateDetail":"1 Jul 2012","EventType":"Graduation","FactTypeId":1000,"Place":{"PlaceName":"St Andrews"}}],"Gender":1,"Id":1,"IsLiving":true,"Names":[{"FactTypeId":100,"GivenNames":"Gavin","Surnames":"Henderson"}]}],"SourceRepos":[]}"#,
+        );
+
+        let actual = gedcom_to_relation_json(&input);
+        assert!(actual.is_ok());
+
+        let actual = actual.unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn omit_empty_values_gedcom_test() {
+        let input = load_fixture("title");
+
+        let actual = gedcom_to_relation_json(&input);
+        assert!(actual.is_ok());
+
+        let actual = actual.unwrap();
+        for array in &[
+            "Childs",
+            "FactTypes",
+            "Familys",
+            "MasterSources",
+            "Medias",
+            "SourceRepos",
+        ] {
+            assert!(actual.contains(&format!(r#""{}":[]"#, array)));
+        }
+
+        assert!(!actual.contains("Facts\""));
+        assert!(!actual.contains("null"));
+    }
+
+    #[test]
+    fn persons_csv_gedcom_test() {
+        let input = load_fixture("one_node");
+        let api_response = gedcom_to_api_response(&input).unwrap();
+
+        let actual = api_response_to_persons_csv(&api_response);
+        let expected = "id,given_names,surnames,gender,birth_date,birth_place,is_living\r\n1,Gavin,Henderson,Male,1 Jan 1990,Dundee,true\r\n";
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn is_gedcom_path_skips_output_files() {
+        let directory = tempfile::tempdir().unwrap();
+
+        for name in ["tree.ged", "tree.json", "tree.csv", "tree.ndjson"].iter() {
+            std::fs::write(directory.path().join(name), "").unwrap();
+        }
+
+        assert!(is_gedcom_path(&directory.path().join("tree.ged")));
+        assert!(!is_gedcom_path(&directory.path().join("tree.json")));
+        assert!(!is_gedcom_path(&directory.path().join("tree.csv")));
+        assert!(!is_gedcom_path(&directory.path().join("tree.ndjson")));
+        assert!(!is_gedcom_path(directory.path()));
+    }
+
+    #[test]
+    fn csv_row_escapes_fields() {
+        let actual = csv_row(&["1", "Henderson, Gavin", "\"Gav\""]);
+        let expected = "1,\"Henderson, Gavin\",\"\"\"Gav\"\"\"\r\n";
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn clone_api_response_test() {
+        let input = load_fixture("three_node");
+        let api_response = gedcom_to_api_response(&input).unwrap();
+
+        let mut cloned = api_response.clone();
+        assert_eq!(cloned, api_response);
+
+        cloned.redact_private();
+        assert_eq!(cloned, api_response);
+        assert!(!format!("{:?}", cloned).is_empty());
+
+        let other = gedcom_to_api_response(&load_fixture("one_node")).unwrap();
+        assert_ne!(other, api_response);
+    }
+
+    #[test]
+    fn supported_version_test() {
+        let input = load_fixture("one_node");
+        let options = ConversionOptions::builder().strict().build();
+
+        let api_response = gedcom_to_api_response_with_options(&input, &options).unwrap();
+        let header = api_response.header().unwrap();
+
+        assert_eq!(header.version(), Some("5.5.1"));
+        assert_eq!(header.unsupported_version(), None);
+        assert!(api_response.warnings().is_empty());
+    }
+
+    #[test]
+    fn unsupported_version_test() {
+        let input = load_fixture("one_node").replace("VERS 5.5.1", "VERS 3.0");
+
+        let options = ConversionOptions::builder().strict().build();
+        let actual = gedcom_to_api_response_with_options(&input, &options);
+        assert_eq!(
+            actual.err().map(|e| e.to_string()),
+            Some(String::from("Unsupported GEDCOM version 3.0"))
+        );
+
+        let api_response = gedcom_to_api_response(&input).unwrap();
+        assert_eq!(
+            api_response.warnings(),
+            &[String::from("Unsupported GEDCOM version 3.0")]
+        );
+    }
+
+    #[test]
+    fn person_by_id_test() {
+        let input = load_fixture("three_node");
+        let api_response = gedcom_to_api_response(&input).unwrap();
+
+        let family = api_response.family_by_id(10_000_001).unwrap();
+        let father = api_response.person_by_id(family.father_id()).unwrap();
+
+        assert_eq!(father.names()[0].given_names(), Some("Frank"));
+        assert_eq!(father.names()[0].surnames(), Some("Henderson"));
+        assert!(api_response.person_by_id(0).is_none());
+        assert!(api_response.family_by_id(0).is_none());
+    }
+
+    #[test]
+    fn spreadsheet_delimiters_test() {
+        let input = "0 HEAD\n1 CHAR UTF-8\n0  @I1@\tINDI\n1\tNAME  Jane /Reed/\n1 SEX\tF\n1 CHAN\n2  DATE 15 APR 2020\n0 TRLR\n";
+
+        let api_response = gedcom_to_api_response(input).unwrap();
+        assert!(api_response.warnings().is_empty());
+
+        let person = &api_response.persons()[0];
+        assert_eq!(person.names()[0].given_names(), Some("Jane"));
+        assert_eq!(person.names()[0].surnames(), Some("Reed"));
+
+        let options = ConversionOptions::builder().strict().build();
+        assert!(gedcom_to_api_response_with_options(input, &options).is_err());
+    }
+
+    #[test]
+    fn trailing_garbage_test() {
+        let input = format!("{}this is not gedcom\n", load_fixture("one_node"));
+        let options = ConversionOptions::builder().strict().build();
+        let actual =
+            gedcom_to_api_response_with_options(&input, &options).map_err(|e| e.to_string());
+
+        assert_eq!(
+            actual.err(),
+            Some(String::from(
+                "Could not parse GEDCOM input: 19 bytes were not consumed"
+            ))
+        );
+
+        let api_response = gedcom_to_api_response(&input).unwrap();
+        assert_eq!(api_response.persons().len(), 1);
+        assert!(api_response
+            .warnings()
+            .contains(&String::from("Could not parse line 39: this is not gedcom")));
+
+        let input = format!("{}\n\n", load_fixture("one_node"));
+        assert!(gedcom_to_api_response(&input).is_ok());
+    }
+
+    #[test]
+    fn limit_test() {
+        let input = load_fixture("sibling");
+        let options = ConversionOptions::builder().with_limit(2).build();
+
+        let api_response = gedcom_to_api_response_with_options(&input, &options).unwrap();
+        let stats = api_response.stats();
+
+        assert_eq!(stats.persons(), 2);
+        assert_eq!(stats.families(), 0);
+        assert_eq!(stats.child_links(), 0);
+
+        let options = ConversionOptions::builder().with_limit(3).build();
+        let api_response = gedcom_to_api_response_with_options(&input, &options).unwrap();
+        let stats = api_response.stats();
+
+        assert_eq!(stats.persons(), 3);
+        assert_eq!(stats.families(), 1);
+        assert_eq!(stats.child_links(), 1);
+
+        for child in api_response.childs() {
+            assert!(api_response.person_by_id(child.child_id()).is_some());
+            assert!(api_response.family_by_id(child.family_id()).is_some());
+        }
+    }
+
+    #[test]
+    fn custom_fields_test() {
+        let input = "0 HEAD\n0 @I1@ INDI\n1 SEX F\n1 _MARNM married-name\n1 CHAN\n2 DATE 15 APR 2020\n0 TRLR\n";
+
+        let options = ConversionOptions::builder().custom_fields().build();
+        let api_response = gedcom_to_api_response_with_options(input, &options).unwrap();
+        let person = &api_response.persons()[0];
+
+        assert_eq!(
+            person.custom_fields().get("_MARNM").map(|v| v.as_str()),
+            Some("married-name")
+        );
+        assert!(serde_json::json!(person)
+            .to_string()
+            .starts_with(r#"{"CustomFields":{"_MARNM":"married-name"},"DateCreated""#));
+
+        let api_response = gedcom_to_api_response(input).unwrap();
+        assert!(api_response.persons()[0].custom_fields().is_empty());
+    }
+
+    #[test]
+    fn missing_trailer_test() {
+        let input = load_fixture("one_node").replace("0 TRLR", "");
+
+        let api_response = gedcom_to_api_response(&input).unwrap();
+
+        assert_eq!(api_response.persons().len(), 1);
+        assert_eq!(api_response.warnings(), &[String::from("Missing trailer")]);
+    }
+
+    #[test]
+    fn content_after_trailer_test() {
+        let input = format!("{}0 @I9@ INDI\n", load_fixture("one_node"));
+
+        let api_response = gedcom_to_api_response(&input).unwrap();
+
+        assert_eq!(
+            api_response.warnings(),
+            &[String::from("Content found after trailer")]
+        );
+    }
+
+    #[test]
+    fn synthetic_ids_follow_input_order_test() {
+        let mut input = String::from("0 HEAD\n");
+        for i in 1..=200 {
+            input.push_str(&format!(
+                "0 @I{}@ INDI\n1 NAME Person{} /Synthetic/\n2 GIVN Person{}\n1 SEX M\n1 CHAN\n2 DATE 15 APR 2020\n",
+                i, i, i
+            ));
+        }
+        input.push_str("0 TRLR\n");
+
+        let api_response = gedcom_to_api_response(&input).unwrap();
+
+        assert_eq!(api_response.persons().len(), 200);
+        for (i, person) in api_response.persons().iter().enumerate() {
+            assert_eq!(person.id(), i as u32 + 1);
+            assert_eq!(
+                person.names()[0].given_names(),
+                Some(format!("Person{}", i + 1).as_str())
+            );
+        }
+    }
+
+    #[test]
+    fn include_submitter_as_person_test() {
+        let input = load_fixture("one_node");
+
+        let api_response = gedcom_to_api_response(&input).unwrap();
+        assert_eq!(api_response.persons().len(), 1);
+
+        let options = ConversionOptions::builder()
+            .include_submitter_as_person()
+            .build();
+        let api_response = gedcom_to_api_response_with_options(&input, &options).unwrap();
+        assert_eq!(api_response.persons().len(), 2);
+
+        let submitter = api_response.person_by_id(30_000_001).unwrap();
+        let expected = r#"{"DateCreated":"2020-04-15T15:21:24","Gender":3,"Id":30000001,"IsLiving":true,"Names":[]}"#;
+        let actual = serde_json::json!(submitter).to_string();
+
+        assert_eq!(actual, expected);
+        assert!(api_response.person_by_id(1).is_some());
+    }
+
+    #[test]
+    fn write_relation_json_test() {
+        for fixture in ["one_node", "three_node", "sibling", "event", "adoption"].iter() {
+            let input = load_fixture(fixture);
+
+            let mut actual = vec![];
+            write_relation_json(&input, &mut actual).unwrap();
+
+            let expected = gedcom_to_relation_json(&input).unwrap();
+            assert_eq!(String::from_utf8(actual).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn write_api_response_json_pretty_test() {
+        let input = load_fixture("three_node");
+        let api_response = gedcom_to_api_response(&input).unwrap();
+
+        let mut actual = vec![];
+        write_api_response_json_pretty(&api_response, &mut actual).unwrap();
+
+        let expected = gedcom_to_relation_json_pretty(&input).unwrap();
+        assert_eq!(String::from_utf8(actual).unwrap(), expected);
+    }
+
+    #[test]
+    fn api_response_round_trip_test() {
+        let expected = r#"{"Childs":[{"ChildId":1,"FamilyId":10000001,"Id":20000001,"RelationshipToFather":1,"RelationshipToMother":1}],"FactTypes":[],"Familys":[{"DateCreated":"2020-04-15T16:40:57","FatherId":3,"Id":10000001,"MotherId":2}],"MasterSources":[],"Medias":[],"Persons":[{"DateCreated":"2020-04-15T16:38:50","Facts":[{"DateDetail":"1 Jan 1990","FactTypeId":405,"Preferred":true}],"Gender":1,"Id":1,"IsLiving":true,"Names":[{"FactTypeId":100,"GivenNames":"Gavin","Surnames":"Henderson"}]},{"DateCreated":"2020-04-15T16:39:15","Facts":[{"FactTypeId":405,"Place":{"PlaceName":"Dundee"},"Preferred":true}],"Gender":2,"Id":2,"IsLiving":true,"Names":[{"FactTypeId":100,"GivenNames":"Jane","Surnames":"Reed"}]},{"DateCreated":"2020-04-15T16:41:07","Gender":1,"Id":3,"IsLiving":true,"Names":[{"FactTypeId":100,"GivenNames":"Frank","Surnames":"Henderson"}]}],"SourceRepos":[]}"#;
+
+        let api_response: ApiResponse = serde_json::from_str(expected).unwrap();
+        assert_eq!(api_response_to_json(&api_response), expected);
+
+        for fixture in [
+            "adoption",
+            "event",
+            "place_form",
+            "restricted",
+            "sibling",
+            "title",
+        ]
+        .iter()
+        {
+            let expected = gedcom_to_relation_json(&load_fixture(fixture)).unwrap();
+            let api_response: ApiResponse = serde_json::from_str(&expected).unwrap();
+
+            assert_eq!(api_response_to_json(&api_response), expected, "{}", fixture);
+        }
+    }
+
+    #[cfg(feature = "schemars")]
+    #[test]
+    fn relation_json_schema_test() {
+        let schema = relation_json_schema();
+
+        assert!(schema["properties"]["Persons"].is_object());
+        assert_eq!(
+            schema["definitions"]["Gender"]["enum"],
+            serde_json::json!([1, 2, 3])
+        );
+        assert_eq!(schema["definitions"]["DateDetail"]["type"], "string");
+    }
+
+    #[test]
+    fn skipped_records_test() {
+        let records = "0 @R1@ REPO\n1 NAME Parish archive\n0 @R2@ REPO\n1 NAME Census office\n0 @N1@ NOTE A note\n0 TRLR\n";
+        let input = load_fixture("one_node").replace("0 TRLR\n", records);
+
+        let api_response = gedcom_to_api_response(&input).unwrap();
+
+        assert_eq!(api_response.persons().len(), 1);
+        assert_eq!(
+            api_response.warnings(),
+            &[
+                String::from("Skipped 1 NOTE record"),
+                String::from("Skipped 2 REPO records"),
+            ]
+        );
+    }
+
+    #[test]
+    fn parent_relationship_labels_test() {
+        let input = "0 HEAD\n0 @I1@ INDI\n1 SEX M\n1 FAMC @F1@\n2 PEDI foster\n1 CHAN\n2 DATE 15 APR 2020\n0 @I2@ INDI\n1 SEX F\n1 FAMC @F1@\n2 PEDI foster\n1 CHAN\n2 DATE 15 APR 2020\n0 @I3@ INDI\n1 SEX M\n1 CHAN\n2 DATE 15 APR 2020\n0 @I4@ INDI\n1 SEX F\n1 CHAN\n2 DATE 15 APR 2020\n0 @F1@ FAM\n1 HUSB @I3@\n1 WIFE @I4@\n1 CHIL @I1@\n2 _FREL Step\n2 _MREL Biological\n1 CHIL @I2@\n1 CHAN\n2 DATE 15 APR 2020\n0 TRLR\n";
+
+        let api_response = gedcom_to_api_response(input).unwrap();
+        let relationships: Vec<(u32, ChildRelationship, ChildRelationship)> = api_response
+            .childs()
+            .iter()
+            .map(|c| {
+                (
+                    c.child_id(),
+                    c.relationship_to_father(),
+                    c.relationship_to_mother(),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            relationships,
+            vec![
+                (1, ChildRelationship::Step, ChildRelationship::Biological),
+                (2, ChildRelationship::Foster, ChildRelationship::Foster),
+            ]
+        );
+    }
+
+    #[test]
+    fn fragment_gedcom_test() {
+        let input = load_fixture("fragment");
+        let expected = r#"{"Childs":[],"FactTypes":[],"Familys":[{"DateCreated":"2020-04-15T00:00:00","FatherId":1,"Id":10000001,"MotherId":2}],"MasterSources":[],"Medias":[],"Persons":[{"DateCreated":"2020-04-15T00:00:00","Facts":[{"DateDetail":"1 Jan 1960","FactTypeId":405,"Preferred":false}],"Gender":1,"Id":1,"IsLiving":true,"Names":[{"FactTypeId":100,"GivenNames":"Frank","Surnames":"Henderson"}]},{"DateCreated":"2020-04-15T00:00:00","Gender":2,"Id":2,"IsLiving":true,"Names":[{"FactTypeId":100,"GivenNames":"Jane","Surnames":"Reed"}]}],"SourceRepos":[]}"#;
+
+        let actual = gedcom_to_relation_json(&input).unwrap();
+        assert_eq!(actual, expected);
+
+        let options = ConversionOptions::builder().strict().build();
+        let api_response = gedcom_to_api_response_with_options(&input, &options).unwrap();
+
+        assert_eq!(api_response.persons().len(), 2);
+        assert_eq!(api_response.familys().len(), 1);
+        assert_eq!(
+            api_response.warnings(),
+            &[
+                String::from("Missing header"),
+                String::from("Missing trailer"),
+            ]
+        );
+    }
+
+    #[test]
+    fn iso_dates_test() {
+        let input = load_fixture("one_node");
+        let options = ConversionOptions::builder().iso_dates().build();
+
+        let actual = gedcom_to_relation_json_with_options(&input, &options).unwrap();
+        let expected = r#"{"Childs":[],"FactTypes":[],"Familys":[],"MasterSources":[],"Medias":[],"Persons":[{"DateCreated":"2020-04-15T16:19:21","Facts":[{"DateDetail":"1990-01-01","FactTypeId":405,"Place":{"PlaceName":"Dundee"},"Preferred":true}],"Gender":1,"Id":1,"IsLiving":true,"Names":[{"FactTypeId":100,"GivenNames":"Gavin","Surnames":"Henderson"}]}],"SourceRepos":[]}"#;
+
+        assert_eq!(actual, expected);
+
+        let api_response: ApiResponse = serde_json::from_str(&actual).unwrap();
+        assert_eq!(api_response_to_json(&api_response), expected);
+    }
+
+    #[test]
+    fn unresolved_family_pointer_test() {
+        let input = load_fixture("three_node").replace("1 CHIL @I1@", "1 CHIL @I1@\n1 CHIL @I9@");
+
+        let api_response = gedcom_to_api_response(&input).unwrap();
+
+        assert_eq!(api_response.childs().len(), 1);
+        assert_eq!(api_response.familys().len(), 1);
+        assert_eq!(
+            api_response.warnings(),
+            &[String::from(
+                "Family 10000001 has CHIL pointer @I9@ which does not match any individual"
+            )]
+        );
+    }
+
+    #[test]
+    fn mismatched_family_pointer_test() {
+        let input = load_fixture("three_node").replace("1 CHIL @I1@", "1 CHIL @I1@\n1 CHIL @F2@");
+
+        let api_response = gedcom_to_api_response(&input).unwrap();
+
+        assert_eq!(api_response.childs().len(), 1);
+        assert_eq!(
+            api_response.warnings(),
+            &[String::from(
+                "Family 10000001 has CHIL pointer @F2@ to a family record, not an individual"
+            )]
+        );
+    }
+
+    #[test]
+    fn windows_1252_bytes_test() {
+        let input = load_fixture_bytes("latin1");
+        assert!(std::str::from_utf8(&input).is_err());
+
+        let actual = gedcom_bytes_to_relation_json(&input).unwrap();
+        let expected = r#"{"Childs":[],"FactTypes":[],"Familys":[],"MasterSources":[],"Medias":[],"Persons":[{"DateCreated":"2020-04-15T16:19:21","Facts":[{"DateDetail":"1 Jan 1990","FactTypeId":405,"Place":{"PlaceName":"Zürich"},"Preferred":false}],"Gender":1,"Id":1,"IsLiving":true,"Names":[{"FactTypeId":100,"GivenNames":"René","Surnames":"Müller"}]}],"SourceRepos":[]}"#;
+
+        assert_eq!(actual, expected);
+
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/latin1.ged");
+        let gedcom = read_gedcom_file(&path).unwrap();
+        assert_eq!(gedcom_to_relation_json(&gedcom).unwrap(), expected);
+    }
+
+    #[test]
+    fn header_language_test() {
+        let input = load_fixture("one_node");
+        let api_response = gedcom_to_api_response(&input).unwrap();
+
+        assert_eq!(api_response.header().unwrap().language(), Some("English"));
+    }
+
+    #[test]
+    fn redact_private_removes_raw_lines_of_private_records_test() {
+        let input = load_fixture("restricted");
+        let options = ConversionOptions::builder().include_raw_lines().build();
+
+        let mut api_response = gedcom_to_api_response_with_options(&input, &options).unwrap();
+        api_response.redact_private();
+
+        let actual = api_response_to_json(&api_response);
+        assert!(!actual.contains("Gavin"));
+        assert!(!actual.contains("@I1@"));
+        assert!(actual.contains(r#"{"Level":0,"Tag":"INDI","Xref":"@I2@"}"#));
+        assert_eq!(api_response.raw_lines().len(), input.lines().count() - 16);
+    }
+
+    #[test]
+    fn include_raw_lines_test() {
+        let input = load_fixture("one_node");
+        let options = ConversionOptions::builder().include_raw_lines().build();
+
+        let api_response = gedcom_to_api_response_with_options(&input, &options).unwrap();
+        assert_eq!(api_response.raw_lines().len(), input.lines().count());
+
+        let value = serde_json::json!(api_response);
+        let raw_lines = value["RawLines"].as_array().unwrap();
+
+        assert_eq!(raw_lines.len(), input.lines().count());
+        assert_eq!(raw_lines[0].to_string(), r#"{"Level":0,"Tag":"HEAD"}"#);
+        assert_eq!(
+            raw_lines[23].to_string(),
+            r#"{"Level":0,"Tag":"INDI","Xref":"@I1@"}"#
+        );
+        assert_eq!(
+            raw_lines[24].to_string(),
+            r#"{"Level":1,"Tag":"NAME","Value":"Gavin /Henderson/"}"#
+        );
+
+        let round_trip: ApiResponse = serde_json::from_value(value).unwrap();
+        assert_eq!(round_trip.raw_lines(), api_response.raw_lines());
+
+        let api_response = gedcom_to_api_response(&input).unwrap();
+        assert!(api_response.raw_lines().is_empty());
+        assert!(!api_response_to_json(&api_response).contains("RawLines"));
+    }
+
+    #[test]
+    fn facts_json_test() {
+        let input = load_fixture("sibling");
+        let api_response = gedcom_to_api_response(&input).unwrap();
+
+        let births: Vec<(u32, String)> = api_response
+            .person_facts()
+            .iter()
+            .filter(|fact| fact.fact_type_id() == FactTypeId::Birth)
+            .map(|fact| {
+                let date = fact.date_detail().map(|d| d.to_string());
+                (fact.person_id(), date.unwrap_or_default())
+            })
+            .collect();
+
+        assert_eq!(births.len(), 3);
+        assert_eq!(births[0], (1, String::from("1 Jan 1990")));
+
+        let actual = api_response_to_facts_json(&api_response);
+        assert!(actual.starts_with(r#"[{"DateDetail":"1 Jan 1990","FactTypeId":405,"PersonId":1}"#));
+    }
+
+    #[test]
+    fn gedcom_7_change_dates_test() {
+        let input = load_fixture("one_node")
+            .replace("2 VERS 5.5.1", "2 VERS 7.0")
+            .replace("DATE 15 APR 2020", "DATE 2020-04-15");
+
+        let api_response = gedcom_to_api_response(&input).unwrap();
+        let header = api_response.header().unwrap();
+
+        assert!(header.date().is_some());
+        assert_eq!(header.unsupported_version(), None);
+        assert!(api_response.warnings().is_empty());
+        assert_eq!(api_response.persons().len(), 1);
+        assert!(
+            api_response_to_json(&api_response).contains(r#""DateCreated":"2020-04-15T16:19:21""#)
+        );
+
+        let options = ConversionOptions::builder().strict().build();
+        let input = input.replace("2 VERS 7.0", "2 VERS 7.0.14");
+        let api_response = gedcom_to_api_response_with_options(&input, &options).unwrap();
+
+        assert!(api_response.warnings().is_empty());
+        assert_eq!(api_response.persons().len(), 1);
+
+        let input = load_fixture("one_node").replace("DATE 15 APR 2020", "DATE 2020-04-15");
+        let api_response = gedcom_to_api_response(&input).unwrap();
+
+        assert!(api_response.persons().is_empty());
+    }
+
+    #[test]
+    fn ndjson_lines_parse_independently() {
+        let input = load_fixture("sibling");
+        let api_response = gedcom_to_api_response(&input).unwrap();
+
+        let mut output = vec![];
+        write_api_response_ndjson(&api_response, &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+
+        let types: Vec<String> = output
+            .lines()
+            .map(|line| {
+                let value: serde_json::Value = serde_json::from_str(line).unwrap();
+                value["Type"].as_str().unwrap().to_owned()
+            })
+            .collect();
+
+        assert_eq!(
+            types,
+            vec!["Person", "Person", "Person", "Person", "Family", "Child", "Child"]
+        );
+        assert_eq!(
+            output.lines().next().unwrap(),
+            r#"{"DateCreated":"2020-04-15T16:42:39","Facts":[{"DateDetail":"1 Jan 1990","FactTypeId":405,"Preferred":true}],"Gender":1,"Id":1,"IsLiving":true,"Names":[{"FactTypeId":100,"GivenNames":"Gavin","Surnames":"Henderson"}],"Type":"Person"}"#
+        );
+    }
+
+    #[test]
+    fn uid_person_ids_test() {
+        let first = load_fixture("one_node");
+        let second = first.replace(
+            "0 @I1@ INDI",
+            "0 @I0@ INDI\n1 NAME Jane /Reed/\n1 SEX F\n1 CHAN\n2 DATE 15 APR 2020\n3 TIME 16:00:00\n0 @I1@ INDI",
+        );
+        let options = ConversionOptions::builder().uid_person_ids().build();
+
+        let gavin_id = |input: &str, options: &ConversionOptions| {
+            gedcom_to_api_response_with_options(input, options)
+                .unwrap()
+                .persons()
+                .iter()
+                .find(|p| p.names()[0].given_names() == Some("Gavin"))
+                .map(|p| p.id())
+                .unwrap()
+        };
+
+        let actual = gavin_id(&first, &options);
+        assert!(actual >= 0x8000_0000);
+        assert_eq!(gavin_id(&second, &options), actual);
+
+        let api_response = gedcom_to_api_response_with_options(&second, &options).unwrap();
+        assert_eq!(api_response.persons()[0].id(), 1);
+
+        let options = ConversionOptions::default();
+        assert_ne!(gavin_id(&first, &options), gavin_id(&second, &options));
+    }
+
+    #[test]
+    fn custom_fact_mapper_test() {
+        #[derive(Debug)]
+        struct BirthMapper;
+
+        impl FactMapper for BirthMapper {
+            fn fact_type_code(&self, fact_type_id: FactTypeId) -> u16 {
+                match fact_type_id {
+                    FactTypeId::Birth => 1,
+                    FactTypeId::SealingSpouse => 2,
+                    _ => fact_type_id.code(),
+                }
+            }
+        }
+
+        let input = load_fixture("one_node");
+        let options = ConversionOptions::builder()
+            .with_fact_mapper(BirthMapper)
+            .build();
+
+        let actual = gedcom_to_relation_json_with_options(&input, &options).unwrap();
+        let expected = r#"{"Childs":[],"FactTypes":[],"Familys":[],"MasterSources":[],"Medias":[],"Persons":[{"DateCreated":"2020-04-15T16:19:21","Facts":[{"DateDetail":"1 Jan 1990","FactTypeId":1,"Place":{"PlaceName":"Dundee"},"Preferred":true}],"Gender":1,"Id":1,"IsLiving":true,"Names":[{"FactTypeId":100,"GivenNames":"Gavin","Surnames":"Henderson"}]}],"SourceRepos":[]}"#;
+
+        assert_eq!(actual, expected);
+
+        let input = "0 HEAD
+1 CHAR UTF-8
+0 @I1@ INDI
+1 NAME John /Reed/
+1 SEX M
+1 FAMS @F1@
+1 CHAN
+2 DATE 15 APR 2020
+0 @I2@ INDI
+1 NAME Jane /Reed/
+1 SEX F
+1 FAMS @F1@
+1 CHAN
+2 DATE 15 APR 2020
+0 @F1@ FAM
+1 HUSB @I1@
+1 WIFE @I2@
+1 SLGS
+2 DATE 1 JAN 1950
+2 TEMP LONDO
+1 CHAN
+2 DATE 15 APR 2020
+0 TRLR
+";
+        let api_response = gedcom_to_api_response_with_options(input, &options).unwrap();
+        let fact_type_ids: Vec<FactTypeId> = api_response.familys()[0]
+            .facts()
+            .iter()
+            .map(Fact::fact_type_id)
+            .collect();
+
+        assert_eq!(fact_type_ids, vec![FactTypeId::Custom(2)]);
+    }
+
+    #[test]
+    fn mapped_fact_types_are_write_only_test() {
+        #[derive(Debug)]
+        struct EmigrationMapper;
+
+        impl FactMapper for EmigrationMapper {
+            fn fact_type_code(&self, fact_type_id: FactTypeId) -> u16 {
+                match fact_type_id {
+                    FactTypeId::Emigration => FactTypeId::Birth.code(),
+                    _ => fact_type_id.code(),
+                }
+            }
+        }
+
+        let input = "0 HEAD
+1 CHAR UTF-8
+0 @I1@ INDI
+1 NAME John /Reed/
+1 SEX M
+1 EMIG
+2 DATE 1 JAN 1950
+2 PLAC Glasgow
+1 CHAN
+2 DATE 15 APR 2020
+0 TRLR
+";
+        let options = ConversionOptions::builder()
+            .with_fact_mapper(EmigrationMapper)
+            .build();
+
+        let api_response = gedcom_to_api_response_with_options(input, &options).unwrap();
+        let fact = &api_response.persons()[0].facts()[0];
+        assert!(matches!(fact, Fact::Event(_)));
+        assert_eq!(fact.fact_type_id(), FactTypeId::Birth);
+
+        let output = gedcom_to_relation_json_with_options(input, &options).unwrap();
+        assert!(serde_json::from_str::<ApiResponse>(&output).is_err());
+    }
+
+    #[test]
+    fn month_locales_test() {
+        let input = load_fixture("one_node").replace("1 Jan 1990", "1 MÄR 1990");
+
+        let api_response = gedcom_to_api_response(&input).unwrap();
+        let date_detail = api_response.persons()[0].facts()[0].date_detail().unwrap();
+        assert_eq!(date_detail.month(), Some(3));
+
+        let options = ConversionOptions::builder()
+            .with_month_locales(&[MonthLocale::English])
+            .build();
+
+        let api_response = gedcom_to_api_response_with_options(&input, &options).unwrap();
+        assert_eq!(api_response.persons()[0].facts()[0].date_detail(), None);
+    }
+
+    #[test]
+    fn omit_is_living_test() {
+        let input = load_fixture("one_node");
+        let options = ConversionOptions::builder().omit_is_living().build();
+
+        let actual = gedcom_to_relation_json_with_options(&input, &options).unwrap();
+        let expected = r#"{"Childs":[],"FactTypes":[],"Familys":[],"MasterSources":[],"Medias":[],"Persons":[{"DateCreated":"2020-04-15T16:19:21","Facts":[{"DateDetail":"1 Jan 1990","FactTypeId":405,"Place":{"PlaceName":"Dundee"},"Preferred":true}],"Gender":1,"Id":1,"Names":[{"FactTypeId":100,"GivenNames":"Gavin","Surnames":"Henderson"}]}],"SourceRepos":[]}"#;
+
+        assert_eq!(actual, expected);
+        assert!(!actual.contains("IsLiving"));
+    }
+
+    #[test]
+    fn source_forms_test() {
+        let input = load_fixture("sources");
+
+        let api_response = gedcom_to_api_response(&input).unwrap();
+
+        let header = api_response.header().unwrap();
+        assert_eq!(header.source(), Some("FINDMYPAST"));
+
+        let sources = api_response.sources();
+        assert_eq!(sources.len(), 1);
+        assert_eq!(sources[0].source_id(), "S1");
+        assert_eq!(sources[0].title(), Some("Parish register of Dundee"));
+        assert_eq!(sources[0].author(), Some("Church of Scotland"));
+
+        let citations = api_response.persons()[0].facts()[0].citations();
+        assert_eq!(citations.len(), 1);
+        assert_eq!(citations[0].source_id(), "S1");
+        assert_eq!(citations[0].page(), Some("Entry 42"));
+
+        assert!(api_response.warnings().is_empty());
+    }
+
+    #[test]
+    fn header_date_fallback_test() {
+        let input =
+            load_fixture("one_node").replace("1 CHAN\n2 DATE 15 APR 2020\n3 TIME 16:19:21\n", "");
+
+        let api_response = gedcom_to_api_response(&input).unwrap();
+        assert!(api_response.persons().is_empty());
+
+        let options = ConversionOptions::builder().header_date_fallback().build();
+        let api_response = gedcom_to_api_response_with_options(&input, &options).unwrap();
+
+        assert_eq!(api_response.persons().len(), 1);
+        assert!(
+            api_response_to_json(&api_response).contains(r#""DateCreated":"2020-04-15T15:21:24""#)
+        );
+    }
+}
@@ -1,55 +1,198 @@
 use clap::{App, Arg};
-use gedcom::gedcom_to_relation_json;
+use gedcom::{
+    api_response_to_families_csv, api_response_to_persons_csv, gedcom_to_api_response_with_options,
+    is_gedcom_path, json_output_path, models::relation::ConversionOptions, output_path,
+    read_gedcom_file, validate_gedcom, write_api_response_json, write_api_response_json_pretty,
+    write_api_response_ndjson,
+};
 use std::{
     error::Error,
     fs,
     fs::File,
-    io::{Read, Write},
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+    process,
 };
 
-const JSON: &'static str = "json";
+const CSV: &str = "csv";
+const FAMILIES_CSV: &str = "families.csv";
+const JSON: &str = "json";
+const NDJSON: &str = "ndjson";
 
 fn main() -> Result<(), Box<dyn Error>> {
     let input_directory_arg = Arg::with_name("directory")
         .help("Specify a directory containing one or more GEDCOM files to convert")
         .long("directory")
-        .required(true)
+        .required_unless("file")
         .short("d")
         .takes_value(true)
         .value_name("directory");
 
+    let input_file_arg = Arg::with_name("file")
+        .help("Specify a single GEDCOM file to convert")
+        .conflicts_with("directory")
+        .long("file")
+        .short("f")
+        .takes_value(true)
+        .value_name("file");
+
+    let format_arg = Arg::with_name("format")
+        .help("Specify the output format, csv writes separate persons and families files and ndjson writes one record per line")
+        .long("format")
+        .possible_values(&[CSV, JSON, NDJSON])
+        .default_value(JSON)
+        .takes_value(true)
+        .value_name("format");
+
+    let limit_arg = Arg::with_name("limit")
+        .help("Only convert the first N individuals of each file, in input order")
+        .long("limit")
+        .takes_value(true)
+        .value_name("N");
+
+    let pretty_arg = Arg::with_name("pretty")
+        .help("Pretty-print the JSON output files")
+        .long("pretty")
+        .short("p");
+
+    let redact_private_arg = Arg::with_name("redact-private")
+        .help("Omit the details of persons marked with a confidential or privacy restriction")
+        .long("redact-private");
+
+    let stats_arg = Arg::with_name("stats")
+        .help("Print a count of persons, families, child links and generations for each file")
+        .long("stats");
+
+    let validate_arg = Arg::with_name("validate")
+        .help("Report problems such as duplicate xrefs and unresolved pointers without converting")
+        .long("validate");
+
     let arguments = App::new("gedcom")
         .version("0.1")
         .arg(input_directory_arg)
+        .arg(input_file_arg)
+        .arg(format_arg)
+        .arg(limit_arg)
+        .arg(pretty_arg)
+        .arg(redact_private_arg)
+        .arg(stats_arg)
+        .arg(validate_arg)
         .get_matches();
 
-    let directory = arguments
-        .value_of("directory")
-        .ok_or("No input directory specified")?;
+    let input_paths = match arguments.value_of("file") {
+        Some(file) => vec![PathBuf::from(file)],
+        None => {
+            let directory = arguments
+                .value_of("directory")
+                .ok_or("No input directory specified")?;
+            gedcom_paths(Path::new(directory))
+        }
+    };
+
+    if arguments.is_present("validate") {
+        if !validate(&input_paths) {
+            process::exit(1);
+        }
+
+        return Ok(());
+    }
+
+    let csv = arguments.value_of("format") == Some(CSV);
+    let ndjson = arguments.value_of("format") == Some(NDJSON);
+    let pretty = arguments.is_present("pretty");
+
+    let mut options = ConversionOptions::builder();
+    if let Some(limit) = arguments.value_of("limit") {
+        let limit = limit.parse().map_err(|_| "Limit must be a whole number")?;
+        options.with_limit(limit);
+    }
+    let options = options.build();
+
+    let redact_private = arguments.is_present("redact-private");
+    let stats = arguments.is_present("stats");
+
+    for input_path in input_paths.iter() {
+        let gedcom = read_gedcom_file(input_path)?;
+
+        let mut api_response = gedcom_to_api_response_with_options(gedcom.as_str(), &options)?;
+
+        if stats {
+            println!("{}: {}", input_path.display(), api_response.stats());
+        }
+
+        if redact_private {
+            api_response.redact_private();
+        }
+
+        if csv {
+            let persons = api_response_to_persons_csv(&api_response);
+            let mut output = File::create(output_path(input_path, CSV))?;
+            output.write_all(persons.as_bytes())?;
+
+            let families = api_response_to_families_csv(&api_response);
+            let mut output = File::create(output_path(input_path, FAMILIES_CSV))?;
+            output.write_all(families.as_bytes())?;
+
+            continue;
+        }
+
+        if ndjson {
+            let mut output = BufWriter::new(File::create(output_path(input_path, NDJSON))?);
+            write_api_response_ndjson(&api_response, &mut output)?;
+            output.flush()?;
+
+            continue;
+        }
+
+        let mut output = BufWriter::new(File::create(json_output_path(input_path))?);
+
+        if pretty {
+            write_api_response_json_pretty(&api_response, &mut output)?;
+        } else {
+            write_api_response_json(&api_response, &mut output)?;
+        }
+
+        output.flush()?;
+    }
+
+    Ok(())
+}
+
+fn gedcom_paths(directory: &Path) -> Vec<PathBuf> {
+    let mut paths = vec![];
 
     if let Ok(dir_entries) = fs::read_dir(directory) {
-        for entry in dir_entries.into_iter() {
-            match entry {
-                Err(_) => {}
-                Ok(entry) => {
-                    let input_path = entry.path();
+        for entry in dir_entries.flatten() {
+            let input_path = entry.path();
+
+            if is_gedcom_path(&input_path) {
+                paths.push(input_path);
+            }
+        }
+    }
 
-                    if input_path.extension().unwrap_or_default() != JSON {
-                        let output_path = input_path.with_extension("json");
+    paths
+}
 
-                        let mut input = File::open(input_path)?;
-                        let mut gedcom = String::new();
-                        input.read_to_string(&mut gedcom)?;
+fn validate(input_paths: &[PathBuf]) -> bool {
+    let mut valid = true;
 
-                        let json = gedcom_to_relation_json(gedcom.as_str())?;
+    for input_path in input_paths.iter() {
+        let warnings = read_gedcom_file(input_path).and_then(|gedcom| validate_gedcom(&gedcom));
 
-                        let mut output = File::create(output_path)?;
-                        output.write_all(json.as_bytes())?;
-                    }
+        match warnings {
+            Ok(warnings) => {
+                for warning in warnings.iter() {
+                    println!("{}: {}", input_path.display(), warning);
+                    valid &= !warning.kind().is_error();
                 }
             }
+            Err(e) => {
+                println!("{}: {}", input_path.display(), e);
+                valid = false;
+            }
         }
     }
 
-    Ok(())
+    valid
 }
@@ -1,2 +1,10 @@
+#[cfg(feature = "std")]
+mod date_time;
+mod error;
 pub mod gedcom;
+#[cfg(feature = "std")]
 pub mod relation;
+
+#[cfg(feature = "std")]
+pub use self::date_time::{DateTime, SimpleDateTime};
+pub use self::error::ModelError;
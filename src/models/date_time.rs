@@ -0,0 +1,289 @@
+use crate::models::relation::DateDetail;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+
+#[cfg(feature = "chrono")]
+use crate::{models::ModelError, DATE_CREATED_FORMAT};
+#[cfg(feature = "chrono")]
+use chrono::{Datelike, NaiveDate, NaiveDateTime, Timelike};
+#[cfg(feature = "chrono")]
+use std::convert::TryFrom;
+
+#[cfg(feature = "chrono")]
+pub type DateTime = NaiveDateTime;
+
+#[cfg(not(feature = "chrono"))]
+pub type DateTime = SimpleDateTime;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct SimpleDateTime {
+    year: i32,
+    month: u32,
+    day: u32,
+    hour: u32,
+    minute: u32,
+    second: u32,
+}
+
+impl SimpleDateTime {
+    pub fn from_ymd_hms_opt(
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        minute: u32,
+        second: u32,
+    ) -> Option<Self> {
+        if !(1..=12).contains(&month) || !(1..=days_in_month(year, month)).contains(&day) {
+            return None;
+        }
+
+        if hour > 23 || minute > 59 || second > 59 {
+            return None;
+        }
+
+        Some(Self {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+        })
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    pub fn hour(&self) -> u32 {
+        self.hour
+    }
+
+    pub fn minute(&self) -> u32 {
+        self.minute
+    }
+
+    pub fn second(&self) -> u32 {
+        self.second
+    }
+
+    fn parse_time(value: &str) -> Option<(u32, u32, u32)> {
+        let parts: Vec<u32> = value
+            .split(':')
+            .map(|p| p.parse::<u32>())
+            .collect::<Result<_, _>>()
+            .ok()?;
+
+        match parts.as_slice() {
+            [hour, minute, second] => Some((*hour, *minute, *second)),
+            _ => None,
+        }
+    }
+
+    pub fn parse_gedcom(date: &str, time: &str) -> Option<Self> {
+        let date: DateDetail = date.parse().ok()?;
+        let (hour, minute, second) = Self::parse_time(time)?;
+
+        if date.dual_year().is_some() || date.is_iso() {
+            return None;
+        }
+
+        Self::from_ymd_hms_opt(
+            date.year(),
+            date.month()?,
+            date.day()?,
+            hour,
+            minute,
+            second,
+        )
+    }
+
+    pub fn parse_date_created(value: &str) -> Option<Self> {
+        let mut parts = value.splitn(2, 'T');
+        let date = parts.next()?;
+        let (hour, minute, second) = Self::parse_time(parts.next()?)?;
+
+        let date: Vec<&str> = date.split('-').collect();
+        match date.as_slice() {
+            [year, month, day] => Self::from_ymd_hms_opt(
+                year.parse().ok()?,
+                month.parse().ok()?,
+                day.parse().ok()?,
+                hour,
+                minute,
+                second,
+            ),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for SimpleDateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            self.year, self.month, self.day, self.hour, self.minute, self.second
+        )
+    }
+}
+
+impl<'de> Deserialize<'de> for SimpleDateTime {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Self::parse_date_created(&s).ok_or_else(|| de::Error::custom("invalid date_created"))
+    }
+}
+
+impl Serialize for SimpleDateTime {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<NaiveDateTime> for SimpleDateTime {
+    fn from(date_time: NaiveDateTime) -> Self {
+        Self {
+            year: date_time.year(),
+            month: date_time.month(),
+            day: date_time.day(),
+            hour: date_time.hour(),
+            minute: date_time.minute(),
+            second: date_time.second(),
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<SimpleDateTime> for NaiveDateTime {
+    type Error = ModelError;
+
+    fn try_from(date_time: SimpleDateTime) -> Result<Self, Self::Error> {
+        NaiveDate::from_ymd_opt(date_time.year, date_time.month, date_time.day)
+            .and_then(|d| d.and_hms_opt(date_time.hour, date_time.minute, date_time.second))
+            .ok_or(ModelError::InvalidDateTime)
+    }
+}
+
+pub(crate) fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+#[cfg(feature = "chrono")]
+pub(crate) fn parse_gedcom_date_time(date: &str, time: &str) -> Option<DateTime> {
+    let date_time = format!("{} {}", date, time);
+    NaiveDateTime::parse_from_str(&date_time, "%-d %b %Y %H:%M:%S").ok()
+}
+
+#[cfg(not(feature = "chrono"))]
+pub(crate) fn parse_gedcom_date_time(date: &str, time: &str) -> Option<DateTime> {
+    SimpleDateTime::parse_gedcom(date, time)
+}
+
+pub(crate) fn parse_iso_date_time(date: &str, time: &str) -> Option<DateTime> {
+    parse_date_created(&format!("{}T{}", date.trim(), time.trim()))
+}
+
+#[cfg(feature = "chrono")]
+pub(crate) fn parse_date_created(value: &str) -> Option<DateTime> {
+    NaiveDateTime::parse_from_str(value, DATE_CREATED_FORMAT).ok()
+}
+
+#[cfg(not(feature = "chrono"))]
+pub(crate) fn parse_date_created(value: &str) -> Option<DateTime> {
+    SimpleDateTime::parse_date_created(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{days_in_month, SimpleDateTime};
+
+    #[test]
+    fn test_days_in_month() {
+        assert_eq!(days_in_month(1900, 2), 28);
+        assert_eq!(days_in_month(2000, 2), 29);
+        assert_eq!(days_in_month(2020, 4), 30);
+        assert_eq!(days_in_month(2020, 13), 0);
+    }
+
+    #[test]
+    fn can_parse_simple_gedcom_date_time() {
+        let actual = SimpleDateTime::parse_gedcom("15 APR 2020", "16:43:01");
+        let expected = SimpleDateTime::from_ymd_hms_opt(2020, 4, 15, 16, 43, 1);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn cannot_parse_invalid_simple_gedcom_date_time() {
+        assert_eq!(
+            SimpleDateTime::parse_gedcom("31 FEB 2020", "00:00:00"),
+            None
+        );
+        assert_eq!(SimpleDateTime::parse_gedcom("1 Jan 1900", "24:00:00"), None);
+        assert_eq!(SimpleDateTime::parse_gedcom("APR 2020", "00:00:00"), None);
+    }
+
+    #[test]
+    fn can_round_trip_simple_date_time() {
+        let input = r#""2020-04-15T16:43:01""#;
+        let actual: SimpleDateTime = serde_json::from_str(input).unwrap();
+        assert_eq!(serde_json::json!(actual).to_string(), input);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn simple_date_time_serializes_like_chrono() {
+        use super::{parse_date_created, parse_gedcom_date_time};
+        use std::convert::TryFrom;
+
+        let chrono = parse_gedcom_date_time("1 Jan 1900", "00:00:00").unwrap();
+        let simple = SimpleDateTime::parse_gedcom("1 Jan 1900", "00:00:00").unwrap();
+
+        assert_eq!(
+            serde_json::json!(chrono).to_string(),
+            serde_json::json!(simple).to_string()
+        );
+        assert_eq!(SimpleDateTime::from(chrono), simple);
+
+        let chrono = parse_date_created("2020-04-15T16:43:01").unwrap();
+        assert_eq!(
+            chrono::NaiveDateTime::try_from(simple).unwrap().to_string(),
+            "1900-01-01 00:00:00"
+        );
+        assert_eq!(
+            serde_json::json!(chrono).to_string(),
+            serde_json::json!(SimpleDateTime::from(chrono)).to_string()
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn out_of_range_simple_date_time_does_not_convert_to_chrono() {
+        use std::convert::TryFrom;
+
+        let simple: SimpleDateTime = serde_json::from_str(r#""300000-01-01T00:00:00""#).unwrap();
+        assert!(chrono::NaiveDateTime::try_from(simple).is_err());
+    }
+}
@@ -0,0 +1,58 @@
+use core::fmt;
+#[cfg(feature = "std")]
+use std::error::Error;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ModelError {
+    InvalidDateTime,
+    MissingField(&'static str),
+    MissingTag(&'static str),
+    MissingValue(&'static str),
+}
+
+impl fmt::Display for ModelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ModelError::InvalidDateTime => write!(f, "Gedcom Change has invalid date_time"),
+            ModelError::MissingField(field) => write!(f, "Missing required field {}", field),
+            ModelError::MissingTag(tag) => write!(f, "Missing required tag {}", tag),
+            ModelError::MissingValue(tag) => write!(f, "Gedcom {} has no value", tag),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for ModelError {}
+
+#[cfg(test)]
+mod tests {
+    use super::ModelError;
+
+    #[test]
+    fn test_display_invalid_date_time() {
+        let actual = ModelError::InvalidDateTime.to_string();
+        let expected = "Gedcom Change has invalid date_time";
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_display_missing_field() {
+        let actual = ModelError::MissingField("date_created").to_string();
+        let expected = "Missing required field date_created";
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_display_missing_tag() {
+        let actual = ModelError::MissingTag("DATE").to_string();
+        let expected = "Missing required tag DATE";
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_display_missing_value() {
+        let actual = ModelError::MissingValue("TIME").to_string();
+        let expected = "Gedcom TIME has no value";
+        assert_eq!(actual, expected);
+    }
+}
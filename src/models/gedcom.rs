@@ -1,40 +1,126 @@
 mod line;
 mod tree;
+mod validation;
+mod xref;
 
 pub use self::line::{GedcomLine, GedcomLineBuilder, GedcomLineTag};
 pub use self::tree::{GedcomTree, GedcomTreeNode, GedcomTreeNodeBuilder};
-use chrono::NaiveDateTime;
+pub use self::validation::{ValidationWarning, WarningKind};
+pub use self::xref::{Xref, XrefKind};
+use crate::models::ModelError;
+#[cfg(feature = "std")]
+use crate::models::{
+    date_time::{parse_gedcom_date_time, parse_iso_date_time},
+    DateTime,
+};
+use alloc::{borrow::ToOwned, string::String};
+
+#[cfg(feature = "std")]
+const DEFAULT_CHANGE_TIME: &str = "00:00:00";
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum DateFormat {
+    #[default]
+    Gedcom55,
+    Gedcom7,
+}
+
+impl DateFormat {
+    pub fn for_version(version: Option<&str>) -> Self {
+        match version {
+            Some(version) if version.trim().starts_with('7') => DateFormat::Gedcom7,
+            _ => DateFormat::Gedcom55,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+pub fn change_node_to_date_time(node: &GedcomTreeNode) -> Result<DateTime, ModelError> {
+    change_node_to_date_time_with_format(node, DateFormat::default())
+}
+
+#[cfg(feature = "std")]
+pub fn change_node_to_date_time_with_format(
+    node: &GedcomTreeNode,
+    format: DateFormat,
+) -> Result<DateTime, ModelError> {
+    let date_node = node
+        .child(&GedcomLineTag::Date)
+        .ok_or(ModelError::MissingTag("DATE"))?;
+
+    date_node_to_date_time_with_format(date_node, format)
+}
 
-pub fn change_node_to_date_time(node: &GedcomTreeNode) -> Result<NaiveDateTime, &'static str> {
-    let date_node = node.children().get(0).ok_or("Gedcom Change has no date")?;
+#[cfg(feature = "std")]
+pub fn date_node_to_date_time(date_node: &GedcomTreeNode) -> Result<DateTime, ModelError> {
+    date_node_to_date_time_with_format(date_node, DateFormat::default())
+}
+
+#[cfg(feature = "std")]
+pub fn date_node_to_date_time_with_format(
+    date_node: &GedcomTreeNode,
+    format: DateFormat,
+) -> Result<DateTime, ModelError> {
     let date = date_node
         .line_value()
         .as_ref()
-        .ok_or("Gedcom Date has no value")?;
+        .ok_or(ModelError::MissingValue("DATE"))?;
 
-    let time_node = date_node
+    let time = match date_node
         .children()
-        .get(0)
-        .ok_or("Gedcom Date has no time")?;
+        .iter()
+        .find(|c| c.tag() == &GedcomLineTag::Time)
+    {
+        Some(time_node) => time_node
+            .line_value()
+            .as_ref()
+            .ok_or(ModelError::MissingValue("TIME"))?
+            .as_str(),
+        None => DEFAULT_CHANGE_TIME,
+    };
+
+    let date_time = match format {
+        DateFormat::Gedcom55 => parse_gedcom_date_time(date, time),
+        DateFormat::Gedcom7 => {
+            parse_iso_date_time(date, time).or_else(|| parse_gedcom_date_time(date, time))
+        }
+    }
+    .ok_or(ModelError::InvalidDateTime)?;
 
-    let time = time_node
+    Ok(date_time)
+}
+
+pub fn reference_node_to_reference_number(
+    node: &GedcomTreeNode,
+) -> Result<(String, Option<String>), ModelError> {
+    let value = node
         .line_value()
         .as_ref()
-        .ok_or("Gedcom Time has no value")?;
+        .map(|v| v.trim())
+        .filter(|v| !v.is_empty())
+        .ok_or(ModelError::MissingValue("REFN"))?;
 
-    let date_time = format!("{} {}", date, time);
-    let date_time = NaiveDateTime::parse_from_str(&date_time, "%-d %b %Y %H:%M:%S")
-        .map_err(|_| "Gedcom Change has invalid date_time")?;
+    let reference_type = node
+        .children()
+        .iter()
+        .find(|c| c.tag() == &GedcomLineTag::Type)
+        .and_then(|c| c.line_value().as_ref())
+        .map(|v| v.trim().to_owned())
+        .filter(|v| !v.is_empty());
 
-    Ok(date_time)
+    Ok((value.to_owned(), reference_type))
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::models::gedcom::{
-        change_node_to_date_time, GedcomLine, GedcomLineTag, GedcomTreeNodeBuilder,
+    use crate::models::{
+        date_time::parse_date_created,
+        gedcom::{
+            change_node_to_date_time, change_node_to_date_time_with_format, DateFormat, GedcomLine,
+            GedcomLineTag, GedcomTree, GedcomTreeNodeBuilder,
+        },
+        ModelError,
     };
-    use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
 
     #[test]
     fn test_change_node_to_date_time() {
@@ -68,10 +154,7 @@ mod tests {
         let actual = change_node_to_date_time(&change);
         assert!(actual.is_ok());
 
-        let expected = NaiveDateTime::new(
-            NaiveDate::from_ymd(1900, 1, 1),
-            NaiveTime::from_hms(0, 0, 0),
-        );
+        let expected = parse_date_created("1900-01-01T00:00:00").unwrap();
 
         let actual = actual.unwrap();
         assert_eq!(actual, expected);
@@ -109,7 +192,7 @@ mod tests {
         let actual = change_node_to_date_time(&change);
         assert!(actual.is_err());
 
-        let expected = "Gedcom Change has invalid date_time";
+        let expected = ModelError::InvalidDateTime;
         let actual = actual.unwrap_err();
         assert_eq!(actual, expected);
     }
@@ -146,7 +229,7 @@ mod tests {
         let actual = change_node_to_date_time(&change);
         assert!(actual.is_err());
 
-        let expected = "Gedcom Change has invalid date_time";
+        let expected = ModelError::InvalidDateTime;
         let actual = actual.unwrap_err();
         assert_eq!(actual, expected);
     }
@@ -183,7 +266,7 @@ mod tests {
         let actual = change_node_to_date_time(&change);
         assert!(actual.is_err());
 
-        let expected = "Gedcom Change has invalid date_time";
+        let expected = ModelError::InvalidDateTime;
         let actual = actual.unwrap_err();
         assert_eq!(actual, expected);
     }
@@ -219,7 +302,7 @@ mod tests {
         let actual = change_node_to_date_time(&change);
         assert!(actual.is_err());
 
-        let expected = "Gedcom Time has no value";
+        let expected = ModelError::MissingValue("TIME");
         let actual = actual.unwrap_err();
         assert_eq!(actual, expected);
     }
@@ -244,10 +327,11 @@ mod tests {
             .build();
 
         let actual = change_node_to_date_time(&change);
-        assert!(actual.is_err());
+        assert!(actual.is_ok());
 
-        let expected = "Gedcom Date has no time";
-        let actual = actual.unwrap_err();
+        let expected = parse_date_created("1900-01-01T00:00:00").unwrap();
+
+        let actual = actual.unwrap();
         assert_eq!(actual, expected);
     }
 
@@ -272,7 +356,7 @@ mod tests {
         let actual = change_node_to_date_time(&change);
         assert!(actual.is_err());
 
-        let expected = "Gedcom Date has no value";
+        let expected = ModelError::MissingValue("DATE");
         let actual = actual.unwrap_err();
         assert_eq!(actual, expected);
     }
@@ -289,8 +373,44 @@ mod tests {
         let actual = change_node_to_date_time(&change);
         assert!(actual.is_err());
 
-        let expected = "Gedcom Change has no date";
+        let expected = ModelError::MissingTag("DATE");
         let actual = actual.unwrap_err();
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn change_date_format_follows_version() {
+        assert_eq!(DateFormat::for_version(Some("7.0")), DateFormat::Gedcom7);
+        assert_eq!(DateFormat::for_version(Some("5.5.1")), DateFormat::Gedcom55);
+        assert_eq!(DateFormat::for_version(None), DateFormat::Gedcom55);
+
+        let input = "0 @I1@ INDI\n1 CHAN\n2 DATE 2020-04-15\n3 TIME 16:43:01\n1 CHAN\n2 DATE 15 APR 2020\n3 TIME 16:43:01\n";
+        let (_, lines) = crate::parser::parse_gedcom(input).unwrap();
+        let tree = GedcomTree::from(lines);
+        let changes = tree.nodes()[0].children();
+        let expected = parse_date_created("2020-04-15T16:43:01").unwrap();
+
+        let actual = change_node_to_date_time_with_format(&changes[0], DateFormat::Gedcom7);
+        assert_eq!(actual, Ok(expected));
+
+        let actual = change_node_to_date_time_with_format(&changes[1], DateFormat::Gedcom7);
+        assert_eq!(actual, Ok(expected));
+
+        let actual = change_node_to_date_time_with_format(&changes[0], DateFormat::Gedcom55);
+        assert_eq!(actual, Err(ModelError::InvalidDateTime));
+
+        let actual = change_node_to_date_time_with_format(&changes[1], DateFormat::Gedcom55);
+        assert_eq!(actual, Ok(expected));
+    }
+
+    #[test]
+    fn change_date_does_not_need_to_be_first_child() {
+        let input = "0 @I1@ INDI\n1 CHAN\n2 NOTE Imported\n2 DATE 15 APR 2020\n3 TIME 16:43:01\n";
+        let (_, lines) = crate::parser::parse_gedcom(input).unwrap();
+        let tree = GedcomTree::from(lines);
+        let expected = parse_date_created("2020-04-15T16:43:01").unwrap();
+
+        let actual = change_node_to_date_time(&tree.nodes()[0].children()[0]);
+        assert_eq!(actual, Ok(expected));
+    }
 }
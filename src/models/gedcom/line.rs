@@ -1,16 +1,29 @@
-use lazy_static::lazy_static;
-use regex::Regex;
-use std::str::FromStr;
+use crate::models::ModelError;
+#[cfg(feature = "std")]
+use crate::writer::{escape_line_value, is_pointer_value};
+use alloc::string::String;
+use core::{fmt, str::FromStr};
+#[cfg(feature = "schemars")]
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+#[cfg(feature = "std")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
-lazy_static! {
-    static ref VALID_CUSTOM_TAG: Regex = Regex::new(r#"^(_[A-Za-z0-9_]+)$"#).unwrap();
-}
-
-#[cfg_attr(test, derive(Debug, Eq, PartialEq))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "std", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "std", serde(rename_all = "PascalCase"))]
 pub struct GedcomLine {
     level: u8,
+    #[cfg_attr(
+        feature = "std",
+        serde(rename = "Value", default, skip_serializing_if = "Option::is_none")
+    )]
     line_value: Option<String>,
     tag: GedcomLineTag,
+    #[cfg_attr(
+        feature = "std",
+        serde(rename = "Xref", default, skip_serializing_if = "Option::is_none")
+    )]
     xref_id: Option<String>,
 }
 
@@ -31,6 +44,32 @@ impl GedcomLine {
         &self.tag
     }
 
+    #[cfg(feature = "std")]
+    pub fn to_line_string(&self) -> String {
+        let mut output = self.level.to_string();
+
+        if let Some(xref_id) = self.xref_id.as_ref() {
+            output.push(' ');
+            output.push_str(xref_id);
+        }
+
+        output.push(' ');
+        output.push_str(&self.tag.to_string());
+
+        if let Some(line_value) = self.line_value.as_ref() {
+            output.push(' ');
+
+            if is_pointer_value(line_value) {
+                output.push_str(line_value);
+            } else {
+                output.push_str(&escape_line_value(line_value));
+            }
+        }
+
+        output.push_str("\r\n");
+        output
+    }
+
     pub fn xref_id(&self) -> &Option<String> {
         &self.xref_id
     }
@@ -49,9 +88,9 @@ impl GedcomLineBuilder {
         Default::default()
     }
 
-    pub fn build(&mut self) -> Result<GedcomLine, &str> {
-        let level = self.level.take().ok_or("GedcomLine must have a level")?;
-        let tag = self.tag.take().ok_or("GedcomLine must have a tag")?;
+    pub fn build(&mut self) -> Result<GedcomLine, ModelError> {
+        let level = self.level.take().ok_or(ModelError::MissingField("level"))?;
+        let tag = self.tag.take().ok_or(ModelError::MissingField("tag"))?;
 
         let gedcom_line = GedcomLine {
             level,
@@ -84,8 +123,7 @@ impl GedcomLineBuilder {
     }
 }
 
-#[derive(Clone, Eq, PartialEq)]
-#[cfg_attr(test, derive(Debug))]
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum GedcomLineTag {
     Abbreviation,
     Address,
@@ -226,6 +264,194 @@ pub enum GedcomLineTag {
     Web,
 }
 
+impl GedcomLineTag {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Abbreviation => "ABBR",
+            Self::Address => "ADDR",
+            Self::Address1 => "ADR1",
+            Self::Address2 => "ADR2",
+            Self::Adoption => "ADOP",
+            Self::AncestralFileNumber => "AFN",
+            Self::Age => "AGE",
+            Self::Agency => "AGNC",
+            Self::Alias => "ALIA",
+            Self::Ancestors => "ANCE",
+            Self::AncestorInterest => "ANCI",
+            Self::Annulment => "ANUL",
+            Self::Associates => "ASSO",
+            Self::Author => "AUTH",
+            Self::BaptismLds => "BAPL",
+            Self::Baptism => "BAPM",
+            Self::BarMitzvah => "BARM",
+            Self::BasMitzvah => "BASM",
+            Self::Birth => "BIRT",
+            Self::Blessing => "BLES",
+            Self::Burial => "BURI",
+            Self::CallNumber => "CALN",
+            Self::Caste => "CAST",
+            Self::Cause => "CAUS",
+            Self::Census => "CENS",
+            Self::Change => "CHAN",
+            Self::Character => "CHAR",
+            Self::Child => "CHIL",
+            Self::Christening => "CHR",
+            Self::AdultChristening => "CHRA",
+            Self::City => "CITY",
+            Self::Concatenation => "CONC",
+            Self::Confirmation => "CONF",
+            Self::ConfirmationLds => "CONL",
+            Self::Continued => "CONT",
+            Self::Copyright => "COPR",
+            Self::Corporate => "CORP",
+            Self::Cremation => "CREM",
+            Self::Country => "CTRY",
+            Self::Data => "DATA",
+            Self::Date => "DATE",
+            Self::Death => "DEAT",
+            Self::Descendants => "DESC",
+            Self::DescendantInterest => "DESI",
+            Self::Destination => "DEST",
+            Self::Divorce => "DIV",
+            Self::DivorceFiled => "DIVF",
+            Self::PhysicalDescription => "DSCR",
+            Self::Education => "EDUC",
+            Self::Email => "EMAI",
+            Self::Emigration => "EMIG",
+            Self::Endowment => "ENDL",
+            Self::Engagement => "ENGA",
+            Self::Event => "EVEN",
+            Self::Fact => "FACT",
+            Self::Family => "FAM",
+            Self::FamilyChild => "FAMC",
+            Self::FamilyFile => "FAMF",
+            Self::FamilySpouse => "FAMS",
+            Self::Facsimile => "FAX",
+            Self::FirstCommunion => "FCOM",
+            Self::File => "FILE",
+            Self::Format => "FORM",
+            Self::Phonetic => "FONE",
+            Self::Gedcom => "GEDC",
+            Self::GivenName => "GIVN",
+            Self::Graduation => "GRAD",
+            Self::Header => "HEAD",
+            Self::Husband => "HUSB",
+            Self::IdentityNumber => "IDNO",
+            Self::Immigration => "IMMI",
+            Self::Individual => "INDI",
+            Self::Language => "LANG",
+            Self::Latitude => "LATI",
+            Self::Longitude => "LONG",
+            Self::Map => "MAP",
+            Self::MarriageBanns => "MARB",
+            Self::MarriageContract => "MARC",
+            Self::MarriageLicense => "MARL",
+            Self::Marriage => "MARR",
+            Self::MarriageSettlement => "MARS",
+            Self::Media => "MEDI",
+            Self::Name => "NAME",
+            Self::Nationality => "NATI",
+            Self::Naturalisation => "NATU",
+            Self::ChildrenCount => "NCHI",
+            Self::Nickname => "NICK",
+            Self::MarriageCount => "NMR",
+            Self::Note => "NOTE",
+            Self::NamePrefix => "NPFX",
+            Self::NameSuffix => "NSFX",
+            Self::Object => "OBJE",
+            Self::Occupation => "OCCU",
+            Self::Ordinance => "ORDI",
+            Self::Ordination => "ORDN",
+            Self::Page => "PAGE",
+            Self::Pedigree => "PEDI",
+            Self::Phone => "PHON",
+            Self::Place => "PLAC",
+            Self::PostalCode => "POST",
+            Self::Probate => "PROB",
+            Self::Property => "PROP",
+            Self::Publication => "PUBL",
+            Self::QualityOfData => "QUAY",
+            Self::Reference => "REFN",
+            Self::Relationship => "RELA",
+            Self::Religion => "RELI",
+            Self::Repository => "REPO",
+            Self::Residence => "RESI",
+            Self::Restriction => "RESN",
+            Self::Retirement => "RETI",
+            Self::RecordFileNumber => "RFN",
+            Self::RecordIdNumber => "RIN",
+            Self::Role => "ROLE",
+            Self::Romanised => "ROMN",
+            Self::Sex => "SEX",
+            Self::SealingChild => "SLGC",
+            Self::SealingSpouse => "SLGS",
+            Self::Source => "SOUR",
+            Self::SurnamePrefix => "SPFX",
+            Self::SocialSecurityNumber => "SSN",
+            Self::State => "STAE",
+            Self::Status => "STAT",
+            Self::Submitter => "SUBM",
+            Self::Submission => "SUBN",
+            Self::Surname => "SURN",
+            Self::Temple => "TEMP",
+            Self::Text => "TEXT",
+            Self::Time => "TIME",
+            Self::Title => "TITL",
+            Self::Trailer => "TRLR",
+            Self::Type => "TYPE",
+            Self::Version => "VERS",
+            Self::Wife => "WIFE",
+            Self::Will => "WILL",
+            Self::Web => "WWW",
+            Self::Custom(custom) => custom.as_str(),
+        }
+    }
+
+    fn is_valid_custom_tag(value: &str) -> bool {
+        value.len() > 1
+            && value.starts_with('_')
+            && value.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    }
+}
+
+impl fmt::Display for GedcomLineTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'de> Deserialize<'de> for GedcomLineTag {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Serialize for GedcomLineTag {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl JsonSchema for GedcomLineTag {
+    fn schema_name() -> String {
+        String::from("GedcomLineTag")
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        String::json_schema(gen)
+    }
+}
+
 impl FromStr for GedcomLineTag {
     type Err = &'static str;
 
@@ -368,7 +594,7 @@ impl FromStr for GedcomLineTag {
             "WILL" => Ok(Self::Will),
             "WWW" => Ok(Self::Web),
             _ => {
-                if VALID_CUSTOM_TAG.is_match(value) {
+                if GedcomLineTag::is_valid_custom_tag(value) {
                     Ok(Self::Custom(String::from(value)))
                 } else {
                     Err("Custom tag value must start with _ and contain only A-Z, a-z, 0-9 and _")
@@ -380,9 +606,59 @@ impl FromStr for GedcomLineTag {
 
 #[cfg(test)]
 mod tests {
-    use super::GedcomLineTag;
+    use super::{GedcomLine, GedcomLineTag};
+    use proptest::{prelude::*, sample::select};
+    use std::collections::{BTreeMap, HashSet};
     use std::str::FromStr;
 
+    const ARBITRARY_TAGS: &[&str] = &[
+        "ADDR", "BIRT", "CHAN", "CHIL", "CHR", "CITY", "CONC", "CONT", "DATE", "DEAT", "EMAI",
+        "FAM", "FAMC", "FAMS", "GIVN", "HEAD", "HUSB", "INDI", "NAME", "NOTE", "PLAC", "REFN",
+        "SEX", "SOUR", "SURN", "TIME", "TRLR", "TYPE", "VERS", "WIFE",
+    ];
+
+    impl Arbitrary for GedcomLineTag {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+            prop_oneof![
+                select(ARBITRARY_TAGS).prop_map(|tag| GedcomLineTag::from_str(tag).unwrap()),
+                "_[A-Z0-9_]{1,8}".prop_map(GedcomLineTag::Custom),
+            ]
+            .boxed()
+        }
+    }
+
+    impl Arbitrary for GedcomLine {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+            let line_value = prop_oneof![
+                "[A-Za-z0-9][A-Za-z0-9 ,.@]{0,20}[A-Za-z0-9]",
+                "@[A-Z][0-9]{1,4}@",
+            ];
+
+            (
+                0u8..4,
+                proptest::option::of(line_value),
+                any::<GedcomLineTag>(),
+                proptest::option::of("@[A-Z][0-9]{1,4}@"),
+            )
+                .prop_map(|(level, line_value, tag, xref_id)| {
+                    GedcomLine::builder()
+                        .with_level(level)
+                        .with_optional_line_value(line_value)
+                        .with_tag(tag)
+                        .with_optional_xref_id(xref_id)
+                        .build()
+                        .unwrap()
+                })
+                .boxed()
+        }
+    }
+
     macro_rules! tag_test {
         ($test_name:ident, $input:literal, $expected:expr) => {
             #[test]
@@ -768,4 +1044,70 @@ mod tests {
     tag_test!(test_from_str_will_lc, "will", GedcomLineTag::Will);
     tag_test!(test_from_str_www, "WWW", GedcomLineTag::Web);
     tag_test!(test_from_str_www_lc, "www", GedcomLineTag::Web);
+
+    #[test]
+    fn gedcom_line_tag_can_be_used_as_map_key() {
+        let tags = vec![
+            GedcomLineTag::Name,
+            GedcomLineTag::Birth,
+            GedcomLineTag::Custom(String::from("_MILT")),
+            GedcomLineTag::Name,
+        ];
+
+        let set: HashSet<GedcomLineTag> = tags.iter().cloned().collect();
+        assert_eq!(set.len(), 3);
+        assert!(set.contains(&GedcomLineTag::Custom(String::from("_MILT"))));
+
+        let mut counts: BTreeMap<GedcomLineTag, usize> = BTreeMap::new();
+        for tag in tags.into_iter() {
+            *counts.entry(tag).or_default() += 1;
+        }
+
+        assert_eq!(counts.get(&GedcomLineTag::Name), Some(&2));
+        assert_eq!(counts.get(&GedcomLineTag::Birth), Some(&1));
+        assert!(GedcomLineTag::Birth < GedcomLineTag::Name);
+    }
+
+    #[test]
+    fn gedcom_line_tag_display_round_trips() {
+        let tags = vec![
+            GedcomLineTag::Address,
+            GedcomLineTag::Christening,
+            GedcomLineTag::Custom(String::from("_PRIM")),
+            GedcomLineTag::Individual,
+            GedcomLineTag::Web,
+        ];
+
+        for tag in tags.into_iter() {
+            let actual = GedcomLineTag::from_str(&tag.to_string());
+            assert_eq!(actual, Ok(tag));
+        }
+
+        assert_eq!(GedcomLineTag::Individual.to_string(), "INDI");
+    }
+
+    #[test]
+    fn to_line_string_writes_xref_tag_and_value() {
+        let actual = GedcomLine::builder()
+            .with_level(0)
+            .with_optional_xref_id(Some(String::from("@N1@")))
+            .with_tag(GedcomLineTag::Note)
+            .with_optional_line_value(Some(String::from("Email jane@example.com")))
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            actual.to_line_string(),
+            "0 @N1@ NOTE Email jane@@example.com\r\n"
+        );
+
+        let actual = GedcomLine::builder()
+            .with_level(1)
+            .with_tag(GedcomLineTag::Husband)
+            .with_optional_line_value(Some(String::from("@I1@")))
+            .build()
+            .unwrap();
+
+        assert_eq!(actual.to_line_string(), "1 HUSB @I1@\r\n");
+    }
 }
@@ -1,4 +1,13 @@
-use crate::models::gedcom::{GedcomLine, GedcomLineTag};
+use crate::models::gedcom::{GedcomLine, GedcomLineTag, ValidationWarning, WarningKind, Xref};
+use alloc::{
+    borrow::ToOwned,
+    collections::{BTreeMap, BTreeSet},
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::slice;
 
 #[cfg_attr(test, derive(Debug, Eq, PartialEq))]
 pub struct GedcomTree {
@@ -6,49 +15,185 @@ pub struct GedcomTree {
 }
 
 impl From<Vec<GedcomLine>> for GedcomTree {
-    fn from(mut lines: Vec<GedcomLine>) -> Self {
-        let mut nodes = vec![];
-        let mut children = vec![];
+    fn from(lines: Vec<GedcomLine>) -> Self {
+        let lines: Vec<(usize, GedcomLine)> = lines
+            .into_iter()
+            .enumerate()
+            .map(|(index, line)| (index + 1, line))
+            .collect();
+
+        Self::from(lines)
+    }
+}
 
-        let mut previous_level = 100;
+impl From<Vec<(usize, GedcomLine)>> for GedcomTree {
+    fn from(lines: Vec<(usize, GedcomLine)>) -> Self {
+        let mut nodes = vec![];
+        let mut stack: Vec<GedcomTreeNodeBuilder> = vec![];
 
-        while let Some(line) = lines.pop() {
+        for (line_number, line) in lines.into_iter() {
             let current_level = line.level();
 
-            if current_level == 0 {
-                let node_children = children.drain(..).collect();
-                let node = GedcomTreeNodeBuilder::from(line)
-                    .with_children(node_children)
-                    .build();
-
-                nodes.push(node);
-            } else if current_level < previous_level {
-                let node_children = children
-                    .drain_filter(|c| c.level() == current_level + 1)
-                    .collect();
-                let node = GedcomTreeNodeBuilder::from(line)
-                    .with_children(node_children)
-                    .build();
-
-                children.push(node);
-            } else {
-                let node = GedcomTreeNodeBuilder::from(line).build();
-                children.push(node);
+            while matches!(stack.last(), Some(b) if b.level >= current_level) {
+                let node = stack.pop().unwrap().build();
+                Self::attach(&mut nodes, &mut stack, node);
             }
 
-            previous_level = current_level;
+            let mut builder = GedcomTreeNodeBuilder::from(line);
+            builder.with_line(line_number);
+            stack.push(builder);
         }
 
-        nodes.reverse();
+        while let Some(mut builder) = stack.pop() {
+            let node = builder.build();
+            Self::attach(&mut nodes, &mut stack, node);
+        }
 
         Self { nodes }
     }
 }
 
+impl From<Vec<GedcomTreeNode>> for GedcomTree {
+    fn from(nodes: Vec<GedcomTreeNode>) -> Self {
+        Self { nodes }
+    }
+}
+
 impl GedcomTree {
+    fn attach(
+        nodes: &mut Vec<GedcomTreeNode>,
+        stack: &mut [GedcomTreeNodeBuilder],
+        node: GedcomTreeNode,
+    ) {
+        match stack.last_mut() {
+            Some(parent) => parent.children.push(node),
+            None => nodes.push(node),
+        }
+    }
+
+    pub fn iter(&self) -> slice::Iter<'_, GedcomTreeNode> {
+        self.nodes.iter()
+    }
+
     pub fn nodes(&self) -> &Vec<GedcomTreeNode> {
         &self.nodes
     }
+
+    pub fn validate(&self) -> Vec<ValidationWarning> {
+        let mut warnings = vec![];
+        let mut defined: BTreeMap<String, usize> = BTreeMap::new();
+        let mut last_line = 0;
+
+        for node in self.nodes.iter() {
+            if let Some(xref) = node.xref() {
+                match defined.get(xref.id()) {
+                    Some(first_line) => warnings.push(ValidationWarning::new(
+                        node.line(),
+                        WarningKind::DuplicateXref,
+                        format!(
+                            "Duplicate xref {}, first defined on line {}",
+                            xref, first_line
+                        ),
+                    )),
+                    None => {
+                        defined.insert(xref.id().to_owned(), node.line());
+                    }
+                }
+            }
+        }
+
+        for node in self.nodes.iter() {
+            if node.level() != 0 {
+                warnings.push(ValidationWarning::new(
+                    node.line(),
+                    WarningKind::BadLevel,
+                    format!("Top-level record has level {}, expected 0", node.level()),
+                ));
+            }
+
+            node.visit(|n, _| {
+                last_line = last_line.max(n.line());
+
+                for child in n.children().iter() {
+                    if child.level() != n.level() + 1 {
+                        warnings.push(ValidationWarning::new(
+                            child.line(),
+                            WarningKind::BadLevel,
+                            format!(
+                                "Line has level {}, expected {}",
+                                child.level(),
+                                n.level() + 1
+                            ),
+                        ));
+                    }
+                }
+            });
+        }
+
+        for (pointer, line) in self.unresolved_pointers().into_iter() {
+            warnings.push(ValidationWarning::new(
+                line,
+                WarningKind::UnresolvedPointer,
+                format!("Pointer {} does not match any record", pointer),
+            ));
+        }
+
+        if !self
+            .nodes
+            .iter()
+            .any(|n| n.tag() == &GedcomLineTag::Trailer)
+        {
+            warnings.push(ValidationWarning::new(
+                last_line,
+                WarningKind::MissingTrailer,
+                String::from("Missing trailer"),
+            ));
+        }
+
+        warnings.sort_by_key(|w| w.line());
+        warnings
+    }
+
+    pub fn unresolved_pointers(&self) -> Vec<(String, usize)> {
+        let defined: BTreeSet<String> = self
+            .nodes
+            .iter()
+            .filter_map(|n| n.xref())
+            .map(|xref| xref.id().to_owned())
+            .collect();
+
+        let mut unresolved = vec![];
+
+        for node in self.nodes.iter() {
+            node.visit(|n, _| {
+                if let Some(pointer) = n.pointer() {
+                    if !defined.contains(pointer.id()) {
+                        unresolved.push((pointer.to_string(), n.line()));
+                    }
+                }
+            });
+        }
+
+        unresolved
+    }
+}
+
+impl IntoIterator for GedcomTree {
+    type Item = GedcomTreeNode;
+    type IntoIter = vec::IntoIter<GedcomTreeNode>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.nodes.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a GedcomTree {
+    type Item = &'a GedcomTreeNode;
+    type IntoIter = slice::Iter<'a, GedcomTreeNode>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
 }
 
 #[derive(Clone)]
@@ -56,6 +201,7 @@ impl GedcomTree {
 pub struct GedcomTreeNode {
     children: Vec<Self>,
     level: u8,
+    line: usize,
     line_value: Option<String>,
     tag: GedcomLineTag,
     xref_id: Option<String>,
@@ -66,18 +212,60 @@ impl GedcomTreeNode {
         &self.children
     }
 
+    pub fn child(&self, tag: &GedcomLineTag) -> Option<&GedcomTreeNode> {
+        self.children.iter().find(|c| c.tag() == tag)
+    }
+
+    pub fn children_with_tag<'a>(
+        &'a self,
+        tag: &'a GedcomLineTag,
+    ) -> impl Iterator<Item = &'a GedcomTreeNode> {
+        self.children.iter().filter(move |c| c.tag() == tag)
+    }
+
+    pub fn child_value(&self, tag: &GedcomLineTag) -> Option<&str> {
+        self.children
+            .iter()
+            .filter(|c| c.tag() == tag)
+            .find_map(|c| c.line_value().as_deref())
+    }
+
     pub fn level(&self) -> u8 {
         self.level
     }
 
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
     pub fn line_value(&self) -> &Option<String> {
         &self.line_value
     }
 
+    pub fn pointer(&self) -> Option<Xref> {
+        self.line_value.as_ref().and_then(|v| v.parse().ok())
+    }
+
     pub fn tag(&self) -> &GedcomLineTag {
         &self.tag
     }
 
+    pub fn visit<F: FnMut(&GedcomTreeNode, u8)>(&self, mut f: F) {
+        self.visit_depth(&mut f, 0);
+    }
+
+    fn visit_depth<F: FnMut(&GedcomTreeNode, u8)>(&self, f: &mut F, depth: u8) {
+        f(self, depth);
+
+        for child in self.children.iter() {
+            child.visit_depth(f, depth + 1);
+        }
+    }
+
+    pub fn xref(&self) -> Option<Xref> {
+        self.xref_id.as_ref().and_then(|v| v.parse().ok())
+    }
+
     pub fn xref_id(&self) -> &Option<String> {
         &self.xref_id
     }
@@ -86,6 +274,7 @@ impl GedcomTreeNode {
 pub struct GedcomTreeNodeBuilder {
     children: Vec<GedcomTreeNode>,
     level: u8,
+    line: usize,
     line_value: Option<String>,
     tag: GedcomLineTag,
     xref_id: Option<String>,
@@ -96,6 +285,7 @@ impl From<GedcomLine> for GedcomTreeNodeBuilder {
         Self {
             children: vec![],
             level: line.level(),
+            line: 0,
             line_value: line.line_value().to_owned(),
             tag: line.tag().to_owned(),
             xref_id: line.xref_id().to_owned(),
@@ -105,9 +295,32 @@ impl From<GedcomLine> for GedcomTreeNodeBuilder {
 
 impl GedcomTreeNodeBuilder {
     pub fn build(&mut self) -> GedcomTreeNode {
+        let mut children = vec![];
+
+        for child in self.children.drain(..) {
+            let separator = match child.tag() {
+                GedcomLineTag::Concatenation => "",
+                GedcomLineTag::Continued => "\n",
+                _ => {
+                    children.push(child);
+                    continue;
+                }
+            };
+
+            let value = child.line_value().as_deref().unwrap_or_default();
+            if separator.is_empty() && value.is_empty() {
+                continue;
+            }
+
+            let folded = self.line_value.get_or_insert_with(String::new);
+            folded.push_str(separator);
+            folded.push_str(value);
+        }
+
         GedcomTreeNode {
-            children: self.children.drain(..).rev().collect(),
+            children,
             level: self.level,
+            line: self.line,
             line_value: self.line_value.to_owned(),
             tag: self.tag.to_owned(),
             xref_id: self.xref_id.to_owned(),
@@ -118,6 +331,11 @@ impl GedcomTreeNodeBuilder {
         self.children = children;
         self
     }
+
+    pub fn with_line(&mut self, line: usize) -> &mut Self {
+        self.line = line;
+        self
+    }
 }
 
 #[cfg(test)]
@@ -138,6 +356,7 @@ mod tests {
         let expected = GedcomTreeNode {
             children: vec![],
             level: 0,
+            line: 0,
             line_value: None,
             tag: GedcomLineTag::Individual,
             xref_id: None,
@@ -157,6 +376,7 @@ mod tests {
         let child = GedcomTreeNode {
             children: vec![],
             level: 1,
+            line: 0,
             line_value: Some(String::from("Name")),
             tag: GedcomLineTag::Name,
             xref_id: None,
@@ -171,6 +391,7 @@ mod tests {
         let expected = GedcomTreeNode {
             children: vec![expected_child],
             level: 0,
+            line: 0,
             line_value: None,
             tag: GedcomLineTag::Individual,
             xref_id: None,
@@ -206,6 +427,7 @@ mod tests {
         let given_name_node = GedcomTreeNode {
             children: vec![],
             level: 2,
+            line: 3,
             line_value: Some(String::from("Given Name")),
             tag: GedcomLineTag::GivenName,
             xref_id: None,
@@ -214,6 +436,7 @@ mod tests {
         let name_node = GedcomTreeNode {
             children: vec![given_name_node],
             level: 1,
+            line: 2,
             line_value: Some(String::from("Name")),
             tag: GedcomLineTag::Name,
             xref_id: None,
@@ -222,6 +445,7 @@ mod tests {
         let indi_node = GedcomTreeNode {
             children: vec![name_node],
             level: 0,
+            line: 1,
             line_value: None,
             tag: GedcomLineTag::Individual,
             xref_id: None,
@@ -234,4 +458,156 @@ mod tests {
 
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn gedcom_tree_node_folds_continuation_lines() {
+        let input = "0 NOTE First line\n1 CONT Second \n1 CONC line\n1 SOUR @S1@\n";
+        let (_, lines) = crate::parser::parse_gedcom(input).unwrap();
+        let tree = GedcomTree::from(lines);
+
+        let actual = tree.nodes()[0].line_value();
+        assert_eq!(actual, &Some(String::from("First line\nSecond line")));
+    }
+
+    #[test]
+    fn gedcom_tree_keeps_numbers_of_lenient_lines() {
+        let input = "0 @I1@ INDI\nnot gedcom\n1 NAME Jane /Reed/\n";
+        let (lines, _) = crate::parser::parse_gedcom_lenient_numbered(input);
+        let tree = GedcomTree::from(lines);
+
+        assert_eq!(tree.nodes()[0].line(), 1);
+        assert_eq!(tree.nodes()[0].children()[0].line(), 3);
+    }
+
+    #[test]
+    fn gedcom_tree_joins_continuation_lines_into_line_value() {
+        let input = "0 @I1@ INDI\n1 NOTE\n2 CONT First\n2 CONC  line\n2 CONT Third line\n2 SOUR @S1@\n1 NOTE Text\n2 SOUR @S2@\n2 CONT More\n";
+        let (_, lines) = crate::parser::parse_gedcom(input).unwrap();
+        let tree = GedcomTree::from(lines);
+        let notes = tree.nodes()[0].children();
+
+        assert_eq!(
+            notes[0].line_value().as_deref(),
+            Some("\nFirst line\nThird line")
+        );
+        assert_eq!(notes[0].children().len(), 1);
+        assert_eq!(notes[0].children()[0].tag(), &GedcomLineTag::Source);
+
+        assert_eq!(notes[1].line_value().as_deref(), Some("Text\nMore"));
+        assert_eq!(notes[1].children().len(), 1);
+    }
+
+    #[test]
+    fn gedcom_tree_preserves_sibling_order() {
+        let input = "0 @I1@ INDI\n1 EVEN\n2 TYPE First\n2 DATE 1 JAN 1900\n1 EVEN\n2 TYPE Second\n1 EVEN\n2 TYPE Third\n2 PLAC Dundee\n3 FORM City\n0 TRLR\n";
+        let (_, lines) = crate::parser::parse_gedcom(input).unwrap();
+        let tree = GedcomTree::from(lines);
+
+        assert_eq!(tree.nodes().len(), 2);
+
+        let event_types: Vec<&str> = tree.nodes()[0]
+            .children()
+            .iter()
+            .map(|event| {
+                assert_eq!(event.tag(), &GedcomLineTag::Event);
+                event.children()[0].line_value().as_deref().unwrap()
+            })
+            .collect();
+        assert_eq!(event_types, vec!["First", "Second", "Third"]);
+
+        let third = &tree.nodes()[0].children()[2];
+        let tags: Vec<&GedcomLineTag> = third.children().iter().map(|c| c.tag()).collect();
+        assert_eq!(tags, vec![&GedcomLineTag::Type, &GedcomLineTag::Place]);
+        assert_eq!(
+            third.children()[1].children()[0].tag(),
+            &GedcomLineTag::Format
+        );
+    }
+
+    #[test]
+    fn gedcom_tree_can_be_iterated() {
+        let input = crate::load_fixture("three_node");
+        let (_, lines) = crate::parser::parse_gedcom(&input).unwrap();
+        let tree = GedcomTree::from(lines);
+
+        let mut count = 0;
+        for node in &tree {
+            assert_eq!(node.level(), 0);
+            count += 1;
+        }
+
+        assert_eq!(count, tree.nodes().len());
+        assert_eq!(tree.iter().count(), count);
+        assert_eq!(
+            tree.into_iter().last().map(|n| n.tag().clone()),
+            Some(GedcomLineTag::Trailer)
+        );
+    }
+
+    #[test]
+    fn gedcom_tree_node_visit_is_pre_order() {
+        let input = crate::load_fixture("sibling");
+        let (_, lines) = crate::parser::parse_gedcom(&input).unwrap();
+        let tree = GedcomTree::from(lines);
+
+        let mut visited = vec![];
+        for node in tree.iter() {
+            node.visit(|n, depth| visited.push((n.tag().clone(), depth)));
+        }
+
+        assert_eq!(visited.len(), input.lines().count());
+        assert_eq!(
+            &visited[..4],
+            &[
+                (GedcomLineTag::Header, 0),
+                (GedcomLineTag::Source, 1),
+                (GedcomLineTag::Name, 2),
+                (GedcomLineTag::Version, 2),
+            ]
+        );
+        assert!(visited.contains(&(GedcomLineTag::City, 4)));
+        assert_eq!(visited.last(), Some(&(GedcomLineTag::Trailer, 0)));
+    }
+
+    #[test]
+    fn can_get_child_value_by_tag() {
+        let input = "0 @I1@ INDI\n1 NAME Jane /Reed/\n1 SEX F\n1 FAMS @F1@\n1 FAMS @F2@\n";
+        let (_, lines) = crate::parser::parse_gedcom(input).unwrap();
+        let tree = GedcomTree::from(lines);
+        let node = &tree.nodes()[0];
+
+        assert_eq!(node.child_value(&GedcomLineTag::Sex), Some("F"));
+        assert_eq!(node.child_value(&GedcomLineTag::Death), None);
+        assert_eq!(
+            node.children_with_tag(&GedcomLineTag::FamilySpouse).count(),
+            2
+        );
+    }
+
+    #[test]
+    fn can_get_child_node_by_tag() {
+        let input = "0 @I1@ INDI\n1 BIRT\n2 PLAC Dundee\n3 MAP\n4 LATI N56.46\n2 PLAC Angus\n";
+        let (_, lines) = crate::parser::parse_gedcom(input).unwrap();
+        let tree = GedcomTree::from(lines);
+        let birth = tree.nodes()[0].child(&GedcomLineTag::Birth).unwrap();
+
+        let place = birth.child(&GedcomLineTag::Place).unwrap();
+        assert_eq!(place.line_value().as_deref(), Some("Dundee"));
+        assert_eq!(place.line(), 3);
+        assert!(place.child(&GedcomLineTag::Map).is_some());
+        assert!(birth.child(&GedcomLineTag::Date).is_none());
+    }
+
+    #[test]
+    fn unresolved_pointers_lists_value_and_line() {
+        let input =
+            "0 @I1@ INDI\n1 FAMS @F1@\n0 @F1@ FAM\n1 HUSB @I1@\n1 CHIL @I9@\n1 SOUR @S1@\n0 TRLR\n";
+        let (_, lines) = crate::parser::parse_gedcom(input).unwrap();
+        let tree = GedcomTree::from(lines);
+
+        assert_eq!(
+            tree.unresolved_pointers(),
+            vec![(String::from("@I9@"), 5), (String::from("@S1@"), 6)]
+        );
+    }
 }
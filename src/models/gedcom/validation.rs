@@ -0,0 +1,127 @@
+use crate::parser::ParseError;
+use alloc::{format, string::String};
+use core::fmt;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WarningKind {
+    BadLevel,
+    DuplicateXref,
+    MissingTrailer,
+    UnparsedLine,
+    UnresolvedPointer,
+}
+
+impl WarningKind {
+    pub fn is_error(&self) -> bool {
+        !matches!(self, WarningKind::MissingTrailer)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ValidationWarning {
+    line: usize,
+    kind: WarningKind,
+    message: String,
+}
+
+impl ValidationWarning {
+    pub fn new(line: usize, kind: WarningKind, message: String) -> Self {
+        Self {
+            line,
+            kind,
+            message,
+        }
+    }
+
+    pub fn kind(&self) -> WarningKind {
+        self.kind
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl From<&ParseError> for ValidationWarning {
+    fn from(error: &ParseError) -> Self {
+        Self::new(
+            error.line_number(),
+            WarningKind::UnparsedLine,
+            format!("Line could not be parsed: {}", error.line().trim()),
+        )
+    }
+}
+
+impl fmt::Display for ValidationWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Line {}: {}", self.line, self.message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ValidationWarning, WarningKind};
+    use crate::{
+        models::gedcom::GedcomTree,
+        parser::{parse_gedcom, ParseError},
+    };
+
+    fn validate(input: &str) -> Vec<ValidationWarning> {
+        let (_, lines) = parse_gedcom(input).unwrap();
+        GedcomTree::from(lines).validate()
+    }
+
+    #[test]
+    fn reports_line_of_duplicate_xref() {
+        let input = "0 HEAD\n1 CHAR UTF-8\n0 @I1@ INDI\n1 NAME Jane /Reed/\n0 @I1@ INDI\n1 NAME John /Reed/\n0 TRLR\n";
+        let actual = validate(input);
+
+        assert_eq!(actual.len(), 1);
+        assert_eq!(actual[0].line(), 5);
+        assert_eq!(actual[0].kind(), WarningKind::DuplicateXref);
+        assert_eq!(
+            actual[0].to_string(),
+            "Line 5: Duplicate xref @I1@, first defined on line 3"
+        );
+    }
+
+    #[test]
+    fn reports_bad_level_unresolved_pointer_and_missing_trailer() {
+        let input = "0 @I1@ INDI\n1 FAMS @F9@\n3 NOTE Skipped a level\n";
+        let actual: Vec<(usize, WarningKind)> = validate(input)
+            .iter()
+            .map(|w| (w.line(), w.kind()))
+            .collect();
+
+        assert_eq!(
+            actual,
+            vec![
+                (2, WarningKind::UnresolvedPointer),
+                (3, WarningKind::BadLevel),
+                (3, WarningKind::MissingTrailer),
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_unparsed_line_as_error() {
+        let actual = ValidationWarning::from(&ParseError::new(4, "not gedcom"));
+
+        assert_eq!(actual.kind(), WarningKind::UnparsedLine);
+        assert!(actual.kind().is_error());
+        assert_eq!(
+            actual.to_string(),
+            "Line 4: Line could not be parsed: not gedcom"
+        );
+    }
+
+    #[test]
+    fn valid_tree_has_no_warnings() {
+        let input = crate::load_fixture("three_node");
+        assert!(validate(&input).is_empty());
+    }
+}
@@ -0,0 +1,147 @@
+use alloc::string::String;
+use core::{fmt, str::FromStr};
+
+const XREF_DELIMITER: char = '@';
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum XrefKind {
+    Family,
+    Individual,
+    Note,
+    Object,
+    Repository,
+    Source,
+    Submission,
+    Submitter,
+    Unknown,
+}
+
+impl From<&str> for XrefKind {
+    fn from(id: &str) -> Self {
+        let id = id.to_uppercase();
+
+        if id.starts_with("SUBM") {
+            XrefKind::Submitter
+        } else if id.starts_with("SUBN") {
+            XrefKind::Submission
+        } else if id.starts_with('F') {
+            XrefKind::Family
+        } else if id.starts_with('I') || id.starts_with('P') {
+            XrefKind::Individual
+        } else if id.starts_with('N') {
+            XrefKind::Note
+        } else if id.starts_with('M') || id.starts_with('O') {
+            XrefKind::Object
+        } else if id.starts_with('R') {
+            XrefKind::Repository
+        } else if id.starts_with('S') {
+            XrefKind::Source
+        } else {
+            XrefKind::Unknown
+        }
+    }
+}
+
+impl fmt::Display for XrefKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self {
+            XrefKind::Family => "family",
+            XrefKind::Individual => "individual",
+            XrefKind::Note => "note",
+            XrefKind::Object => "object",
+            XrefKind::Repository => "repository",
+            XrefKind::Source => "source",
+            XrefKind::Submission => "submission",
+            XrefKind::Submitter => "submitter",
+            XrefKind::Unknown => "unknown",
+        };
+
+        write!(f, "{}", kind)
+    }
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Xref {
+    id: String,
+    kind: XrefKind,
+}
+
+impl Xref {
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn kind(&self) -> XrefKind {
+        self.kind
+    }
+}
+
+impl FromStr for Xref {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim();
+
+        let id = value
+            .strip_prefix(XREF_DELIMITER)
+            .and_then(|v| v.strip_suffix(XREF_DELIMITER))
+            .ok_or("Xref must be delimited by @ characters")?;
+
+        if id.is_empty() || id.contains(XREF_DELIMITER) {
+            return Err("Xref must contain a non-empty id without @ characters");
+        }
+
+        Ok(Self {
+            id: String::from(id),
+            kind: XrefKind::from(id),
+        })
+    }
+}
+
+impl fmt::Display for Xref {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}{}", XREF_DELIMITER, self.id, XREF_DELIMITER)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Xref, XrefKind};
+
+    #[test]
+    fn can_parse_individual_xref() {
+        let actual = "@I1@".parse::<Xref>().unwrap();
+        assert_eq!(actual.id(), "I1");
+        assert_eq!(actual.kind(), XrefKind::Individual);
+        assert_eq!(actual.to_string(), "@I1@");
+    }
+
+    #[test]
+    fn can_parse_family_xref() {
+        let actual = "@F1@".parse::<Xref>().unwrap();
+        assert_eq!(actual.id(), "F1");
+        assert_eq!(actual.kind(), XrefKind::Family);
+        assert_eq!(actual.to_string(), "@F1@");
+    }
+
+    #[test]
+    fn can_parse_submitter_xref() {
+        let actual = "@SUBM1@".parse::<Xref>().unwrap();
+        assert_eq!(actual.id(), "SUBM1");
+        assert_eq!(actual.kind(), XrefKind::Submitter);
+        assert_eq!(actual.to_string(), "@SUBM1@");
+    }
+
+    #[test]
+    fn cannot_parse_undelimited_xref() {
+        assert!("I1".parse::<Xref>().is_err());
+        assert!("@@".parse::<Xref>().is_err());
+    }
+
+    #[test]
+    fn individual_and_family_xrefs_differ() {
+        let individual = "@I1@".parse::<Xref>().unwrap();
+        let family = "@F1@".parse::<Xref>().unwrap();
+        assert_ne!(individual, family);
+    }
+}
@@ -1,9 +1,41 @@
+mod address;
 mod api_response;
+mod citation;
+mod date;
 mod fact;
+mod fact_mapper;
 mod family;
+mod family_link;
+mod header;
+mod media;
+mod options;
 mod person;
+mod person_fact;
+mod restriction;
+#[cfg(feature = "schemars")]
+mod schema;
+mod source;
+mod stats;
+mod submitter;
 
+pub use self::address::Address;
 pub use self::api_response::ApiResponse;
-pub use self::fact::{Birth, BirthBuilder, FactTypeId, Name, Place};
-pub use self::family::{Child, Family};
+pub use self::citation::Citation;
+pub use self::date::{DateDetail, MonthLocale};
+pub use self::fact::{
+    AdoptedBy, Adoption, AdoptionBuilder, Birth, BirthBuilder, Death, DeathBuilder, Event,
+    EventBuilder, Fact, FactTypeId, Name, Ordinance, OrdinanceBuilder, Place, PlaceComponent,
+    PlaceForm,
+};
+pub use self::fact_mapper::{DefaultFactMapper, FactMapper};
+pub use self::family::{Child, ChildRelationship, Family};
+pub use self::family_link::{FamilyLink, FamilyLinkKind, FamilyLinkStatus};
+pub use self::header::Header;
+pub use self::media::{Media, MediaKind};
+pub use self::options::{ConversionOptions, ConversionOptionsBuilder};
 pub use self::person::{Gender, Person, PersonBuilder};
+pub use self::person_fact::PersonFact;
+pub use self::restriction::Restriction;
+pub use self::source::Source;
+pub use self::stats::Stats;
+pub use self::submitter::Submitter;
@@ -0,0 +1,90 @@
+use crate::models::gedcom::{GedcomLineTag, GedcomTreeNode};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Address {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    city: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    country: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    lines: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    postal_code: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    state: Option<String>,
+}
+
+impl From<&GedcomTreeNode> for Address {
+    fn from(node: &GedcomTreeNode) -> Self {
+        let mut address = Self {
+            lines: node
+                .line_value()
+                .as_deref()
+                .map(|v| v.trim().to_owned())
+                .filter(|v| !v.is_empty()),
+            ..Default::default()
+        };
+
+        for child in node.children().iter() {
+            let value = match child.line_value() {
+                Some(value) if !value.trim().is_empty() => Some(value.trim().to_owned()),
+                _ => continue,
+            };
+
+            match child.tag() {
+                GedcomLineTag::City => address.city = value,
+                GedcomLineTag::Country => address.country = value,
+                GedcomLineTag::PostalCode => address.postal_code = value,
+                GedcomLineTag::State => address.state = value,
+                _ => {}
+            }
+        }
+
+        address
+    }
+}
+
+impl Address {
+    pub fn city(&self) -> Option<&str> {
+        self.city.as_deref()
+    }
+
+    pub fn country(&self) -> Option<&str> {
+        self.country.as_deref()
+    }
+
+    pub fn lines(&self) -> Option<&str> {
+        self.lines.as_deref()
+    }
+
+    pub fn postal_code(&self) -> Option<&str> {
+        self.postal_code.as_deref()
+    }
+
+    pub fn state(&self) -> Option<&str> {
+        self.state.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Address;
+    use crate::{models::gedcom::GedcomTree, parser::parse_gedcom};
+
+    #[test]
+    fn can_parse_multi_line_address_with_city() {
+        let input = "0 ADDR 1 High Street\n1 CONT Broughty Ferry\n1 CITY Dundee\n1 POST DD5 1AA\n1 CTRY Scotland\n";
+        let (_, lines) = parse_gedcom(input).unwrap();
+        let tree = GedcomTree::from(lines);
+
+        let actual = Address::from(&tree.nodes()[0]);
+
+        assert_eq!(actual.lines(), Some("1 High Street\nBroughty Ferry"));
+        assert_eq!(actual.city(), Some("Dundee"));
+        assert_eq!(actual.postal_code(), Some("DD5 1AA"));
+        assert_eq!(actual.country(), Some("Scotland"));
+        assert_eq!(actual.state(), None);
+    }
+}
@@ -1,13 +1,28 @@
 use crate::models::{
-    gedcom::{change_node_to_date_time, GedcomLineTag, GedcomTree},
-    relation::{Child, Family, Person, PersonBuilder},
+    gedcom::{
+        change_node_to_date_time_with_format, reference_node_to_reference_number, DateFormat,
+        GedcomLine, GedcomLineTag, GedcomTree, GedcomTreeNode, Xref, XrefKind,
+    },
+    relation::{
+        AdoptedBy, Child, ChildRelationship, ConversionOptions, Fact, Family, FamilyLinkKind,
+        Gender, Header, MonthLocale, Name, Ordinance, Person, PersonBuilder, PersonFact,
+        Restriction, Source, Stats, Submitter,
+    },
 };
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::convert::TryFrom;
 
-#[derive(Default, Deserialize, Serialize)]
-#[cfg_attr(test, derive(Debug))]
+const FNV_OFFSET_BASIS: u32 = 0x811c_9dc5;
+const FNV_PRIME: u32 = 0x0100_0193;
+const UID_PERSON_ID_BASE: u32 = 0x8000_0000;
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[serde(rename_all = "PascalCase")]
 pub struct ApiResponse {
     childs: Vec<Child>,
@@ -16,69 +31,268 @@ pub struct ApiResponse {
     master_sources: Vec<()>,
     medias: Vec<()>,
     persons: Vec<Person>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    raw_lines: Vec<GedcomLine>,
     source_repos: Vec<()>,
+    #[serde(skip)]
+    header: Option<Header>,
+    #[serde(skip)]
+    sources: Vec<Source>,
+    #[serde(skip)]
+    warnings: Vec<String>,
 }
 
 impl From<GedcomTree> for ApiResponse {
     fn from(tree: GedcomTree) -> Self {
-        let mut person_id: u32 = 1;
-        let mut family_id: u32 = 10_000_001;
-        let mut child_id: u32 = 20_000_001;
+        Self::from_tree(tree, &ConversionOptions::default())
+    }
+}
+
+impl ApiResponse {
+    pub fn from_tree(tree: GedcomTree, options: &ConversionOptions) -> Self {
+        let mut next_person_id = options.person_id_base();
+        let mut family_id = options.family_id_base();
+        let mut child_id = options.child_id_base();
+        let mut submitter_id = options.submitter_id_base();
+        let id_step = options.id_step();
 
         let mut childs = vec![];
         let mut familys = vec![];
         let mut persons = vec![];
-        let mut persons_id_map: HashMap<&str, u32> = HashMap::new();
+        let mut persons_id_map: HashMap<Xref, u32> = HashMap::new();
+        let mut uid_person_ids: HashSet<u32> = HashSet::new();
+        let mut adoptions_map: HashMap<(u32, Xref), AdoptedBy> = HashMap::new();
+        let mut pedigrees_map: HashMap<(u32, Xref), ChildRelationship> = HashMap::new();
+        let mut parents_map: HashMap<u32, Vec<u32>> = HashMap::new();
+        let mut header: Option<Header> = None;
+        let mut submitters: HashMap<Xref, Submitter> = HashMap::new();
+        let mut submitter_persons = vec![];
+        let mut sources = vec![];
+        let mut skipped: BTreeMap<String, usize> = BTreeMap::new();
+        let mut warnings = vec![];
+
+        if !tree
+            .nodes()
+            .iter()
+            .any(|node| node.tag() == &GedcomLineTag::Header)
+        {
+            warnings.push(String::from("Missing header"));
+        }
+
+        match tree
+            .nodes()
+            .iter()
+            .position(|node| node.tag() == &GedcomLineTag::Trailer)
+        {
+            None => warnings.push(String::from("Missing trailer")),
+            Some(position) if position + 1 < tree.nodes().len() => {
+                warnings.push(String::from("Content found after trailer"))
+            }
+            Some(_) => {}
+        }
+
+        let individual_xrefs: HashSet<Xref> = tree
+            .nodes()
+            .iter()
+            .filter(|node| node.tag() == &GedcomLineTag::Individual)
+            .filter_map(|node| node.xref())
+            .collect();
+
+        let date_format = tree
+            .nodes()
+            .iter()
+            .find(|node| node.tag() == &GedcomLineTag::Header)
+            .map_or_else(DateFormat::default, |node| Header::from(node).date_format());
+
+        let fallback_date_created = |header: &Option<Header>| {
+            header
+                .as_ref()
+                .and_then(Header::date)
+                .filter(|_| options.header_date_fallback())
+        };
 
-        for node in tree.nodes().into_iter() {
+        let mut person_builders =
+            PersonBuilders::new(tree.nodes(), date_format, options.month_locales());
+        let mut builder = Family::builder();
+
+        for node in tree.nodes().iter() {
+            let person_builder = person_builders.next(node);
             let tag = node.tag().clone();
 
             match tag {
+                GedcomLineTag::Header => {
+                    let node_header = Header::from(node);
+
+                    if let Some(version) = node_header.unsupported_version() {
+                        warnings.push(format!("Unsupported GEDCOM version {}", version));
+                    }
+
+                    header = Some(node_header);
+                }
                 GedcomLineTag::Individual => {
-                    if let Some(xref_id) = node.xref_id() {
-                        if let Ok(mut builder) = PersonBuilder::try_from(node) {
+                    if matches!(options.limit(), Some(limit) if persons.len() >= limit) {
+                        continue;
+                    }
+
+                    if let Some(xref) = node.xref() {
+                        if let Some(builder) = person_builder {
+                            let uid_person_id = Self::uid_person_id(node)
+                                .filter(|_| options.uid_person_ids())
+                                .filter(|id| !uid_person_ids.contains(id));
+                            let person_id = uid_person_id.unwrap_or(next_person_id);
+
                             builder.with_id(person_id);
-                            if let Ok(person) = builder.build() {
+                            if let Some(date_created) = fallback_date_created(&header) {
+                                builder.with_default_date_created(date_created);
+                            }
+
+                            if let Ok(mut person) = builder.build() {
+                                if options.skip_empty_facts() {
+                                    person.remove_empty_facts();
+                                }
+
+                                if !options.custom_fields() {
+                                    person.remove_custom_fields();
+                                }
+
+                                if let Some(place_form) =
+                                    header.as_ref().and_then(|h| h.place_form())
+                                {
+                                    person.apply_place_form(place_form);
+                                }
+
+                                if options.iso_dates() {
+                                    person.use_iso_dates();
+                                }
+
+                                if let Some(mapper) = options.fact_mapper() {
+                                    person.map_fact_types(mapper);
+                                }
+
+                                if options.omit_is_living() {
+                                    person.remove_is_living();
+                                }
+
+                                for adoption in person.adoptions() {
+                                    if let Some(family) = adoption.family() {
+                                        adoptions_map.insert(
+                                            (person_id, family.clone()),
+                                            adoption.adopted_by(),
+                                        );
+                                    }
+                                }
+
+                                for link in person.family_links() {
+                                    if link.kind() != FamilyLinkKind::Child {
+                                        continue;
+                                    }
+
+                                    if let Some(pedigree) = link.pedigree() {
+                                        pedigrees_map
+                                            .insert((person_id, link.family().clone()), pedigree);
+                                    }
+                                }
+
                                 persons.push(person);
-                                persons_id_map.insert(xref_id, person_id);
-                                person_id += 1;
+                                persons_id_map.insert(xref, person_id);
+
+                                if uid_person_id.is_some() {
+                                    uid_person_ids.insert(person_id);
+                                } else {
+                                    next_person_id += id_step;
+                                }
                             }
                         }
                     }
                 }
                 GedcomLineTag::Family => {
-                    let mut builder = Family::builder();
+                    builder.reset();
                     builder.with_id(family_id);
 
+                    let mut child_ids = vec![];
+                    let mut parent_ids = vec![];
+                    let mut unconverted_parent = false;
+
                     for child in node.children().into_iter() {
                         let tag = child.tag().clone();
 
                         match tag {
+                            GedcomLineTag::Custom(tag) if options.custom_fields() => {
+                                if let Some(value) = child.line_value().as_deref() {
+                                    builder.with_custom_field(&tag, value);
+                                }
+                            }
                             GedcomLineTag::Change => {
-                                if let Ok(date_created) = change_node_to_date_time(child) {
+                                if let Ok(date_created) =
+                                    change_node_to_date_time_with_format(child, date_format)
+                                {
                                     builder.with_date_created(date_created);
                                 }
                             }
                             GedcomLineTag::Child => {
-                                if let Some(xref_id) = child.line_value() {
-                                    if let Some(person_id) = persons_id_map.get(xref_id.as_str()) {
-                                        let child = Child::new(*person_id, child_id, family_id);
-                                        childs.push(child);
-                                        child_id += 1;
+                                if let Some(xref) = child.pointer() {
+                                    if let Some(person_id) = persons_id_map.get(&xref) {
+                                        child_ids.push((
+                                            *person_id,
+                                            Self::parent_relationship(child, "_FREL"),
+                                            Self::parent_relationship(child, "_MREL"),
+                                        ));
+                                    } else if !individual_xrefs.contains(&xref) {
+                                        warnings.push(Self::unresolved_pointer_warning(
+                                            family_id, child, &xref,
+                                        ));
                                     }
                                 }
                             }
                             GedcomLineTag::Husband => {
-                                if let Some(xref_id) = child.line_value() {
-                                    if let Some(person_id) = persons_id_map.get(xref_id.as_str()) {
+                                if let Some(xref) = child.pointer() {
+                                    if let Some(person_id) = persons_id_map.get(&xref) {
                                         builder.with_father_id(*person_id);
+                                        parent_ids.push(*person_id);
+                                    } else {
+                                        if !individual_xrefs.contains(&xref) {
+                                            warnings.push(Self::unresolved_pointer_warning(
+                                                family_id, child, &xref,
+                                            ));
+                                        }
+
+                                        unconverted_parent = true;
                                     }
                                 }
                             }
+                            GedcomLineTag::Reference => {
+                                if let Ok((value, reference_type)) =
+                                    reference_node_to_reference_number(child)
+                                {
+                                    builder
+                                        .with_reference_number(&value, reference_type.as_deref());
+                                }
+                            }
+                            GedcomLineTag::Restriction => {
+                                if let Some(restriction) = child.line_value() {
+                                    if let Ok(restriction) = restriction.parse() {
+                                        builder.with_restriction(restriction);
+                                    }
+                                }
+                            }
+                            GedcomLineTag::SealingSpouse => {
+                                let ordinance =
+                                    Ordinance::from_node(child, options.month_locales());
+                                builder.with_fact(Fact::Ordinance(ordinance));
+                            }
                             GedcomLineTag::Wife => {
-                                if let Some(xref_id) = child.line_value() {
-                                    if let Some(person_id) = persons_id_map.get(xref_id.as_str()) {
+                                if let Some(xref) = child.pointer() {
+                                    if let Some(person_id) = persons_id_map.get(&xref) {
                                         builder.with_mother_id(*person_id);
+                                        parent_ids.push(*person_id);
+                                    } else {
+                                        if !individual_xrefs.contains(&xref) {
+                                            warnings.push(Self::unresolved_pointer_warning(
+                                                family_id, child, &xref,
+                                            ));
+                                        }
+
+                                        unconverted_parent = true;
                                     }
                                 }
                             }
@@ -86,20 +300,407 @@ impl From<GedcomTree> for ApiResponse {
                         }
                     }
 
-                    if let Ok(family) = builder.build() {
+                    if options.limit().is_some() && unconverted_parent {
+                        continue;
+                    }
+
+                    for (person_id, father_relationship, mother_relationship) in child_ids {
+                        let cycle = parent_ids
+                            .iter()
+                            .find_map(|id| Self::ancestor_path(&parents_map, *id, person_id));
+
+                        if let Some(cycle) = cycle {
+                            warnings.push(format!(
+                                "Family {} would make person {} their own ancestor via persons {:?}",
+                                family_id, person_id, cycle
+                            ));
+                            continue;
+                        }
+
+                        let mut child = Child::new(person_id, child_id, family_id);
+
+                        if let Some(family_xref) = node.xref() {
+                            let key = (person_id, family_xref);
+
+                            if let Some(pedigree) = pedigrees_map.get(&key) {
+                                child
+                                    .with_relationship_to_father(*pedigree)
+                                    .with_relationship_to_mother(*pedigree);
+                            }
+
+                            if let Some(adopted_by) = adoptions_map.get(&key) {
+                                child.adopted_by(*adopted_by);
+                            }
+                        }
+
+                        if let Some(relationship) = father_relationship {
+                            child.with_relationship_to_father(relationship);
+                        }
+
+                        if let Some(relationship) = mother_relationship {
+                            child.with_relationship_to_mother(relationship);
+                        }
+
+                        childs.push(child);
+                        child_id += id_step;
+
+                        parents_map
+                            .entry(person_id)
+                            .or_default()
+                            .extend(parent_ids.iter());
+                    }
+
+                    if let Some(date_created) = fallback_date_created(&header) {
+                        builder.with_default_date_created(date_created);
+                    }
+
+                    if let Ok(mut family) = builder.build() {
+                        if options.iso_dates() {
+                            family.use_iso_dates();
+                        }
+
+                        if let Some(mapper) = options.fact_mapper() {
+                            family.map_fact_types(mapper);
+                        }
+
                         familys.push(family);
-                        family_id += 1;
+                        family_id += id_step;
                     }
                 }
-                _ => {}
+                GedcomLineTag::Submitter => {
+                    if let Some(xref) = node.xref() {
+                        if let Ok(submitter) = Submitter::try_from(node) {
+                            if options.include_submitter_as_person() {
+                                let person = Self::submitter_person(
+                                    node,
+                                    &submitter,
+                                    submitter_id,
+                                    header.as_ref(),
+                                );
+
+                                if let Some(mut person) = person {
+                                    if let Some(mapper) = options.fact_mapper() {
+                                        person.map_fact_types(mapper);
+                                    }
+
+                                    if options.omit_is_living() {
+                                        person.remove_is_living();
+                                    }
+
+                                    submitter_persons.push(person);
+                                    submitter_id += id_step;
+                                }
+                            }
+
+                            submitters.insert(xref, submitter);
+                        }
+                    }
+                }
+                GedcomLineTag::Source => match Source::try_from(node) {
+                    Ok(source) => sources.push(source),
+                    Err(_) => *skipped.entry(tag.as_str().to_owned()).or_default() += 1,
+                },
+                GedcomLineTag::Trailer => {}
+                _ => *skipped.entry(tag.as_str().to_owned()).or_default() += 1,
+            }
+        }
+
+        for (tag, count) in skipped.into_iter() {
+            let noun = if count == 1 { "record" } else { "records" };
+            warnings.push(format!("Skipped {} {} {}", count, tag, noun));
+        }
+
+        persons.append(&mut submitter_persons);
+
+        if let Some(ref mut header) = header {
+            let submitter = header
+                .submitter_xref()
+                .and_then(|xref| submitters.remove(xref));
+
+            if let Some(submitter) = submitter {
+                header.with_submitter(submitter);
             }
         }
 
         Self {
             childs,
             familys,
+            header,
             persons,
+            sources,
+            warnings,
             ..Default::default()
         }
     }
+
+    pub fn childs(&self) -> &[Child] {
+        &self.childs
+    }
+
+    pub fn family_by_id(&self, id: u32) -> Option<&Family> {
+        self.familys.iter().find(|family| family.id() == id)
+    }
+
+    pub fn familys(&self) -> &[Family] {
+        &self.familys
+    }
+
+    pub fn person_by_id(&self, id: u32) -> Option<&Person> {
+        self.persons.iter().find(|person| person.id() == id)
+    }
+
+    pub fn persons(&self) -> &[Person] {
+        &self.persons
+    }
+
+    pub fn raw_lines(&self) -> &[GedcomLine] {
+        &self.raw_lines
+    }
+
+    pub fn with_raw_lines(&mut self, raw_lines: Vec<GedcomLine>) -> &mut Self {
+        self.raw_lines = raw_lines;
+        self
+    }
+
+    pub fn with_warning(&mut self, warning: String) -> &mut Self {
+        self.warnings.push(warning);
+        self
+    }
+
+    pub fn header(&self) -> Option<&Header> {
+        self.header.as_ref()
+    }
+
+    pub fn sources(&self) -> &[Source] {
+        &self.sources
+    }
+
+    pub fn warnings(&self) -> &[String] {
+        &self.warnings
+    }
+
+    fn unresolved_pointer_warning(family_id: u32, node: &GedcomTreeNode, xref: &Xref) -> String {
+        match xref.kind() {
+            XrefKind::Individual | XrefKind::Unknown => format!(
+                "Family {} has {} pointer {} which does not match any individual",
+                family_id,
+                node.tag().as_str(),
+                xref
+            ),
+            kind => format!(
+                "Family {} has {} pointer {} to a {} record, not an individual",
+                family_id,
+                node.tag().as_str(),
+                xref,
+                kind
+            ),
+        }
+    }
+
+    fn submitter_person(
+        node: &GedcomTreeNode,
+        submitter: &Submitter,
+        id: u32,
+        header: Option<&Header>,
+    ) -> Option<Person> {
+        let date_format = header.map_or_else(DateFormat::default, Header::date_format);
+        let date_created = node
+            .children()
+            .iter()
+            .find(|c| c.tag() == &GedcomLineTag::Change)
+            .and_then(|c| change_node_to_date_time_with_format(c, date_format).ok())
+            .or_else(|| header.and_then(|h| h.date()))?;
+
+        let mut builder = Person::builder();
+        builder
+            .with_date_created(date_created)
+            .with_gender(Gender::Other)
+            .with_id(id);
+
+        if let Some(name) = submitter.name() {
+            builder.with_name(Name::builder().with_given_names(name).build());
+        }
+
+        builder.build().ok()
+    }
+
+    fn uid_person_id(node: &GedcomTreeNode) -> Option<u32> {
+        let uid = node
+            .children()
+            .iter()
+            .find(|c| matches!(c.tag(), GedcomLineTag::Custom(custom) if custom == "_UID"))
+            .and_then(|c| c.line_value().as_ref())
+            .map(|v| v.trim().to_uppercase())
+            .filter(|v| !v.is_empty())?;
+
+        let hash = uid.bytes().fold(FNV_OFFSET_BASIS, |hash, byte| {
+            (hash ^ u32::from(byte)).wrapping_mul(FNV_PRIME)
+        });
+
+        Some(UID_PERSON_ID_BASE | hash)
+    }
+
+    fn parent_relationship(node: &GedcomTreeNode, tag: &str) -> Option<ChildRelationship> {
+        node.children()
+            .iter()
+            .find(|c| matches!(c.tag(), GedcomLineTag::Custom(custom) if custom == tag))
+            .and_then(|c| c.line_value().as_ref())
+            .and_then(|v| v.parse().ok())
+    }
+
+    fn is_private_record(lines: &[GedcomLine]) -> bool {
+        let is_individual = matches!(
+            lines.first(),
+            Some(line) if line.tag() == &GedcomLineTag::Individual
+        );
+
+        is_individual
+            && lines.iter().any(|line| {
+                let restriction = line.line_value().as_deref().map(str::parse::<Restriction>);
+
+                line.level() == 1
+                    && line.tag() == &GedcomLineTag::Restriction
+                    && matches!(restriction, Some(Ok(restriction)) if restriction.is_private())
+            })
+    }
+
+    fn ancestor_path(
+        parents_map: &HashMap<u32, Vec<u32>>,
+        person_id: u32,
+        ancestor_id: u32,
+    ) -> Option<Vec<u32>> {
+        let mut stack = vec![vec![person_id]];
+        let mut visited = HashSet::new();
+
+        while let Some(path) = stack.pop() {
+            let current = *path.last().unwrap();
+
+            if current == ancestor_id {
+                return Some(path);
+            }
+
+            if visited.insert(current) {
+                for parent_id in parents_map.get(&current).into_iter().flatten() {
+                    let mut path = path.clone();
+                    path.push(*parent_id);
+                    stack.push(path);
+                }
+            }
+        }
+
+        None
+    }
+
+    pub fn redact_private(&mut self) {
+        for person in self.persons.iter_mut() {
+            if person.is_private() {
+                person.redact();
+            }
+        }
+
+        let mut raw_lines = vec![];
+        let mut record = vec![];
+
+        for line in self.raw_lines.drain(..) {
+            if line.level() == 0 {
+                if Self::is_private_record(&record) {
+                    record.clear();
+                } else {
+                    raw_lines.append(&mut record);
+                }
+            }
+
+            record.push(line);
+        }
+
+        if !Self::is_private_record(&record) {
+            raw_lines.append(&mut record);
+        }
+
+        self.raw_lines = raw_lines;
+    }
+
+    pub fn person_facts(&self) -> Vec<PersonFact> {
+        self.persons
+            .iter()
+            .flat_map(|person| {
+                person
+                    .facts()
+                    .iter()
+                    .map(move |fact| PersonFact::new(person.id(), fact))
+            })
+            .collect()
+    }
+
+    pub fn stats(&self) -> Stats {
+        Stats::new(&self.childs, &self.familys, &self.persons)
+    }
+}
+
+#[cfg(feature = "rayon")]
+struct PersonBuilders {
+    builders: std::vec::IntoIter<Option<PersonBuilder>>,
+    current: Option<PersonBuilder>,
+}
+
+#[cfg(feature = "rayon")]
+impl PersonBuilders {
+    fn new(
+        nodes: &[GedcomTreeNode],
+        date_format: DateFormat,
+        month_locales: &[MonthLocale],
+    ) -> Self {
+        let builders: Vec<Option<PersonBuilder>> = nodes
+            .par_iter()
+            .map(|node| match node.tag() {
+                GedcomLineTag::Individual => {
+                    PersonBuilder::from_node(node, date_format, month_locales).ok()
+                }
+                _ => None,
+            })
+            .collect();
+
+        Self {
+            builders: builders.into_iter(),
+            current: None,
+        }
+    }
+
+    fn next(&mut self, _node: &GedcomTreeNode) -> Option<&mut PersonBuilder> {
+        self.current = self.builders.next().flatten();
+        self.current.as_mut()
+    }
+}
+
+#[cfg(not(feature = "rayon"))]
+struct PersonBuilders<'a> {
+    builder: PersonBuilder,
+    date_format: DateFormat,
+    month_locales: &'a [MonthLocale],
+}
+
+#[cfg(not(feature = "rayon"))]
+impl<'a> PersonBuilders<'a> {
+    fn new(
+        _nodes: &[GedcomTreeNode],
+        date_format: DateFormat,
+        month_locales: &'a [MonthLocale],
+    ) -> Self {
+        Self {
+            builder: Person::builder(),
+            date_format,
+            month_locales,
+        }
+    }
+
+    fn next(&mut self, node: &GedcomTreeNode) -> Option<&mut PersonBuilder> {
+        if node.tag() != &GedcomLineTag::Individual {
+            return None;
+        }
+
+        self.builder.reset();
+        self.builder
+            .read_node(node, self.date_format, self.month_locales)
+            .ok()
+    }
 }
@@ -0,0 +1,134 @@
+use crate::models::{
+    gedcom::{GedcomLineTag, GedcomTreeNode},
+    ModelError,
+};
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+
+const MAX_QUALITY: u8 = 3;
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "PascalCase")]
+pub struct Citation {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    page: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    quality: Option<u8>,
+    source_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    text: Option<String>,
+}
+
+impl TryFrom<&GedcomTreeNode> for Citation {
+    type Error = ModelError;
+
+    fn try_from(node: &GedcomTreeNode) -> Result<Self, Self::Error> {
+        if node.tag() != &GedcomLineTag::Source {
+            return Err(ModelError::MissingTag("SOUR"));
+        }
+
+        let source = node.pointer().ok_or(ModelError::MissingValue("SOUR"))?;
+        let mut citation = Citation::new(source.id());
+
+        for child in node.children().iter() {
+            match child.tag() {
+                GedcomLineTag::Data => {
+                    citation.text = child
+                        .children()
+                        .iter()
+                        .find(|c| c.tag() == &GedcomLineTag::Text)
+                        .and_then(|c| c.line_value().as_deref())
+                        .filter(|v| !v.trim().is_empty())
+                        .map(str::to_owned);
+                }
+                GedcomLineTag::Page => {
+                    if let Some(page) = child.line_value().as_deref() {
+                        citation.with_page(page);
+                    }
+                }
+                GedcomLineTag::QualityOfData => {
+                    if let Some(quality) = child.line_value().as_ref() {
+                        citation.with_quality_from_str(quality);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(citation)
+    }
+}
+
+impl Citation {
+    pub fn new(source_id: &str) -> Self {
+        Self {
+            page: None,
+            quality: None,
+            source_id: source_id.to_owned(),
+            text: None,
+        }
+    }
+
+    pub fn page(&self) -> Option<&str> {
+        self.page.as_deref()
+    }
+
+    pub fn quality(&self) -> Option<u8> {
+        self.quality
+    }
+
+    pub fn source_id(&self) -> &str {
+        &self.source_id
+    }
+
+    pub fn text(&self) -> Option<&str> {
+        self.text.as_deref()
+    }
+
+    pub fn with_page(&mut self, page: &str) -> &mut Self {
+        let page = page.trim();
+        if !page.is_empty() {
+            self.page = Some(page.to_owned());
+        }
+        self
+    }
+
+    pub fn with_quality_from_str(&mut self, quality: &str) -> &mut Self {
+        self.quality = quality.trim().parse().ok().filter(|q| *q <= MAX_QUALITY);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Citation;
+    use crate::{models::gedcom::GedcomTree, parser::parse_gedcom};
+    use std::convert::TryFrom;
+
+    #[test]
+    fn can_parse_citation_with_page_quality_and_text() {
+        let input =
+            "0 @I1@ INDI\n1 SOUR @S1@\n2 PAGE Entry 42\n2 DATA\n3 TEXT Born at home\n2 QUAY 3\n";
+        let (_, lines) = parse_gedcom(input).unwrap();
+        let tree = GedcomTree::from(lines);
+
+        let actual = Citation::try_from(&tree.nodes()[0].children()[0]).unwrap();
+
+        assert_eq!(actual.source_id(), "S1");
+        assert_eq!(actual.page(), Some("Entry 42"));
+        assert_eq!(actual.quality(), Some(3));
+        assert_eq!(actual.text(), Some("Born at home"));
+    }
+
+    #[test]
+    fn cannot_parse_citation_without_pointer() {
+        let input = "0 @I1@ INDI\n1 SOUR Family bible\n";
+        let (_, lines) = parse_gedcom(input).unwrap();
+        let tree = GedcomTree::from(lines);
+
+        assert!(Citation::try_from(&tree.nodes()[0].children()[0]).is_err());
+    }
+}
@@ -0,0 +1,440 @@
+use crate::models::date_time::days_in_month;
+use lazy_static::lazy_static;
+use regex::Regex;
+#[cfg(feature = "schemars")]
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::{fmt, str::FromStr};
+
+const MONTHS: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+
+const MONTH_NAMES: [&str; 12] = [
+    "JANUARY",
+    "FEBRUARY",
+    "MARCH",
+    "APRIL",
+    "MAY",
+    "JUNE",
+    "JULY",
+    "AUGUST",
+    "SEPTEMBER",
+    "OCTOBER",
+    "NOVEMBER",
+    "DECEMBER",
+];
+
+const FRENCH_MONTHS: [&str; 12] = [
+    "JANV", "FÉVR", "MARS", "AVR", "MAI", "JUIN", "JUIL", "AOÛT", "SEPT", "OCT", "NOV", "DÉC",
+];
+
+const FRENCH_MONTH_NAMES: [&str; 12] = [
+    "JANVIER",
+    "FÉVRIER",
+    "MARS",
+    "AVRIL",
+    "MAI",
+    "JUIN",
+    "JUILLET",
+    "AOÛT",
+    "SEPTEMBRE",
+    "OCTOBRE",
+    "NOVEMBRE",
+    "DÉCEMBRE",
+];
+
+const GERMAN_MONTHS: [&str; 12] = [
+    "JAN", "FEB", "MÄR", "APR", "MAI", "JUN", "JUL", "AUG", "SEP", "OKT", "NOV", "DEZ",
+];
+
+const GERMAN_MONTH_NAMES: [&str; 12] = [
+    "JANUAR",
+    "FEBRUAR",
+    "MÄRZ",
+    "APRIL",
+    "MAI",
+    "JUNI",
+    "JULI",
+    "AUGUST",
+    "SEPTEMBER",
+    "OKTOBER",
+    "NOVEMBER",
+    "DEZEMBER",
+];
+
+lazy_static! {
+    static ref ISO_DATE: Regex = Regex::new(r#"^([0-9]{4})-([0-9]{2})-([0-9]{2})$"#).unwrap();
+    static ref YEAR: Regex = Regex::new(r#"^([0-9]{1,4})(?:/([0-9]{2}))?$"#).unwrap();
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MonthLocale {
+    English,
+    French,
+    German,
+}
+
+impl MonthLocale {
+    pub const ALL: [MonthLocale; 3] = [
+        MonthLocale::English,
+        MonthLocale::French,
+        MonthLocale::German,
+    ];
+
+    fn month_tokens(&self) -> [&'static [&'static str; 12]; 2] {
+        match self {
+            MonthLocale::English => [&MONTHS, &MONTH_NAMES],
+            MonthLocale::French => [&FRENCH_MONTHS, &FRENCH_MONTH_NAMES],
+            MonthLocale::German => [&GERMAN_MONTHS, &GERMAN_MONTH_NAMES],
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DateDetail {
+    day: Option<u32>,
+    dual_year: Option<i32>,
+    iso: bool,
+    month: Option<u32>,
+    year: i32,
+}
+
+impl DateDetail {
+    pub fn day(&self) -> Option<u32> {
+        self.day
+    }
+
+    pub fn dual_year(&self) -> Option<i32> {
+        self.dual_year
+    }
+
+    pub fn is_iso(&self) -> bool {
+        self.iso
+    }
+
+    pub fn month(&self) -> Option<u32> {
+        self.month
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    pub fn to_iso_string(&self) -> Option<String> {
+        match (self.day, self.month, self.dual_year) {
+            (Some(day), Some(month), None) => {
+                Some(format!("{:04}-{:02}-{:02}", self.year, month, day))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn use_iso(&mut self) {
+        self.iso = true;
+    }
+
+    pub fn parse_with_locales(value: &str, locales: &[MonthLocale]) -> Result<Self, &'static str> {
+        if let Some(captures) = ISO_DATE.captures(value.trim()) {
+            return Self::parse_iso(&captures);
+        }
+
+        let parts: Vec<&str> = value.split_whitespace().collect();
+
+        let (day, month, year) = match parts.as_slice() {
+            [year] => (None, None, *year),
+            [month, year] => (None, Some(Self::parse_month(month, locales)?), *year),
+            [day, month, year] => {
+                let day = day.parse::<u32>().map_err(|_| "Date has an invalid day")?;
+                (Some(day), Some(Self::parse_month(month, locales)?), *year)
+            }
+            _ => return Err("Date must consist of an optional day and month and a year"),
+        };
+
+        let (year, dual_year) = Self::parse_year(year)?;
+
+        if let (Some(day), Some(month)) = (day, month) {
+            if day < 1 || day > days_in_month(dual_year.unwrap_or(year), month) {
+                return Err("Date has an invalid day");
+            }
+        }
+
+        Ok(Self {
+            day,
+            dual_year,
+            iso: false,
+            month,
+            year,
+        })
+    }
+
+    fn parse_iso(captures: &regex::Captures) -> Result<Self, &'static str> {
+        let year = captures[1]
+            .parse::<i32>()
+            .map_err(|_| "Date has an invalid year")?;
+        let month = captures[2]
+            .parse::<u32>()
+            .ok()
+            .filter(|m| (1..=12).contains(m))
+            .ok_or("Date has an invalid month")?;
+        let day = captures[3]
+            .parse::<u32>()
+            .ok()
+            .filter(|d| *d >= 1 && *d <= days_in_month(year, month))
+            .ok_or("Date has an invalid day")?;
+
+        Ok(Self {
+            day: Some(day),
+            dual_year: None,
+            iso: true,
+            month: Some(month),
+            year,
+        })
+    }
+
+    fn parse_month(value: &str, locales: &[MonthLocale]) -> Result<u32, &'static str> {
+        let value = value.to_uppercase();
+
+        for locale in locales.iter() {
+            for tokens in locale.month_tokens().iter() {
+                if let Some(i) = tokens.iter().position(|m| *m == value) {
+                    return Ok(i as u32 + 1);
+                }
+            }
+        }
+
+        Err("Date has an invalid month")
+    }
+
+    fn parse_year(value: &str) -> Result<(i32, Option<i32>), &'static str> {
+        let captures = YEAR.captures(value).ok_or("Date has an invalid year")?;
+        let year = captures[1]
+            .parse::<i32>()
+            .map_err(|_| "Date has an invalid year")?;
+
+        let dual_year = match captures.get(2) {
+            Some(suffix) => {
+                let suffix = suffix
+                    .as_str()
+                    .parse::<i32>()
+                    .map_err(|_| "Date has an invalid dual year")?;
+                let mut dual_year = year - year % 100 + suffix;
+                if dual_year <= year {
+                    dual_year += 100;
+                }
+                Some(dual_year)
+            }
+            None => None,
+        };
+
+        Ok((year, dual_year))
+    }
+}
+
+impl FromStr for DateDetail {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse_with_locales(value, &MonthLocale::ALL)
+    }
+}
+
+impl fmt::Display for DateDetail {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.iso {
+            if let Some(iso) = self.to_iso_string() {
+                return write!(f, "{}", iso);
+            }
+        }
+
+        if let Some(day) = self.day {
+            write!(f, "{} ", day)?;
+        }
+
+        if let Some(month) = self.month {
+            let month = MONTHS[month as usize - 1];
+            write!(f, "{}{} ", &month[..1], month[1..].to_lowercase())?;
+        }
+
+        write!(f, "{}", self.year)?;
+
+        if let Some(dual_year) = self.dual_year {
+            write!(f, "/{:02}", dual_year % 100)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'de> Deserialize<'de> for DateDetail {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for DateDetail {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl JsonSchema for DateDetail {
+    fn schema_name() -> String {
+        String::from("DateDetail")
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        String::json_schema(gen)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DateDetail, MonthLocale};
+
+    #[test]
+    fn can_parse_full_date() {
+        let actual = "1 JAN 1990".parse::<DateDetail>().unwrap();
+
+        assert_eq!(actual.day(), Some(1));
+        assert_eq!(actual.month(), Some(1));
+        assert_eq!(actual.year(), 1990);
+        assert_eq!(actual.dual_year(), None);
+        assert_eq!(actual.to_string(), "1 Jan 1990");
+    }
+
+    #[test]
+    fn can_parse_full_month_name() {
+        let actual = "1 January 1990".parse::<DateDetail>().unwrap();
+        assert_eq!(actual.to_string(), "1 Jan 1990");
+    }
+
+    #[test]
+    fn can_parse_german_month() {
+        let actual = "1 MÄR 1990".parse::<DateDetail>().unwrap();
+        assert_eq!(actual.to_string(), "1 Mar 1990");
+
+        let actual = "3 Dezember 1850".parse::<DateDetail>().unwrap();
+        assert_eq!(actual.to_string(), "3 Dec 1850");
+    }
+
+    #[test]
+    fn can_parse_french_month() {
+        let actual = "14 juil 1789".parse::<DateDetail>().unwrap();
+        assert_eq!(actual.to_string(), "14 Jul 1789");
+    }
+
+    #[test]
+    fn cannot_parse_month_outside_locales() {
+        let actual = DateDetail::parse_with_locales("1 MÄR 1990", &[MonthLocale::English]);
+        assert_eq!(actual, Err("Date has an invalid month"));
+
+        let actual = DateDetail::parse_with_locales("1 AVR 1990", &[MonthLocale::French]);
+        assert_eq!(actual.map(|d| d.month()), Ok(Some(4)));
+    }
+
+    #[test]
+    fn can_parse_month_and_year() {
+        let actual = "MAR 1850".parse::<DateDetail>().unwrap();
+        assert_eq!(actual.to_string(), "Mar 1850");
+    }
+
+    #[test]
+    fn can_parse_dual_year() {
+        let actual = "1700/01".parse::<DateDetail>().unwrap();
+
+        assert_eq!(actual.day(), None);
+        assert_eq!(actual.month(), None);
+        assert_eq!(actual.year(), 1700);
+        assert_eq!(actual.dual_year(), Some(1701));
+        assert_eq!(actual.to_string(), "1700/01");
+    }
+
+    #[test]
+    fn can_parse_full_date_with_dual_year() {
+        let actual = "11 FEB 1731/32".parse::<DateDetail>().unwrap();
+
+        assert_eq!(actual.day(), Some(11));
+        assert_eq!(actual.month(), Some(2));
+        assert_eq!(actual.year(), 1731);
+        assert_eq!(actual.dual_year(), Some(1732));
+        assert_eq!(actual.to_string(), "11 Feb 1731/32");
+    }
+
+    #[test]
+    fn can_parse_dual_year_across_century() {
+        let actual = "1699/00".parse::<DateDetail>().unwrap();
+
+        assert_eq!(actual.dual_year(), Some(1700));
+        assert_eq!(actual.to_string(), "1699/00");
+    }
+
+    #[test]
+    fn can_parse_leap_day_in_dual_year() {
+        let actual = "29 FEB 1739/40".parse::<DateDetail>();
+        assert!(actual.is_ok());
+    }
+
+    #[test]
+    fn cannot_parse_invalid_day() {
+        let actual = "31 FEB 1990".parse::<DateDetail>();
+        assert_eq!(actual, Err("Date has an invalid day"));
+    }
+
+    #[test]
+    fn cannot_parse_invalid_month() {
+        let actual = "1 FOO 1990".parse::<DateDetail>();
+        assert_eq!(actual, Err("Date has an invalid month"));
+    }
+
+    #[test]
+    fn cannot_parse_invalid_year() {
+        let actual = "1 JAN 1990/1".parse::<DateDetail>();
+        assert_eq!(actual, Err("Date has an invalid year"));
+    }
+
+    #[test]
+    fn can_deserialize_date_detail() {
+        let expected = "11 FEB 1731/32".parse::<DateDetail>().unwrap();
+        let actual: DateDetail = serde_json::from_str(r#""11 Feb 1731/32""#).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn can_serialize_date_detail() {
+        let input = "11 FEB 1731/32".parse::<DateDetail>().unwrap();
+        let actual = serde_json::json!(input).to_string();
+        assert_eq!(actual, r#""11 Feb 1731/32""#);
+    }
+
+    #[test]
+    fn can_format_full_date_as_iso() {
+        let mut actual = "1 JAN 1990".parse::<DateDetail>().unwrap();
+        assert_eq!(actual.to_iso_string(), Some(String::from("1990-01-01")));
+
+        actual.use_iso();
+        assert!(actual.is_iso());
+        assert_eq!(actual.to_string(), "1990-01-01");
+        assert_eq!(actual.to_string().parse::<DateDetail>(), Ok(actual));
+
+        let mut actual = "MAR 1850".parse::<DateDetail>().unwrap();
+        actual.use_iso();
+        assert_eq!(actual.to_iso_string(), None);
+        assert_eq!(actual.to_string(), "Mar 1850");
+
+        let mut actual = "11 FEB 1750/51".parse::<DateDetail>().unwrap();
+        actual.use_iso();
+        assert_eq!(actual.to_string(), "11 Feb 1750/51");
+
+        let actual = "1990-02-30".parse::<DateDetail>();
+        assert_eq!(actual, Err("Date has an invalid day"));
+    }
+}
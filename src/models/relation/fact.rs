@@ -1,39 +1,498 @@
-use crate::models::gedcom::{GedcomLineTag, GedcomTreeNode};
-use chrono::NaiveDate;
+#[cfg(feature = "schemars")]
+use crate::models::relation::schema::integer_enum_schema;
+use crate::models::{
+    gedcom::{date_node_to_date_time, GedcomLineTag, GedcomTreeNode, Xref},
+    relation::{Citation, DateDetail, FactMapper, MonthLocale},
+    DateTime,
+};
+use lazy_static::lazy_static;
+use regex::Regex;
+#[cfg(feature = "schemars")]
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
-use serde_repr::{Deserialize_repr, Serialize_repr};
+use std::{convert::TryFrom, str::FromStr};
 
-const DATE_DETAIL_FORMAT: &'static str = "%-d %b %Y";
+const MAX_QUALITY: u8 = 3;
 
-#[derive(Clone, Copy, Deserialize_repr, Serialize_repr)]
-#[cfg_attr(test, derive(Debug, Eq, PartialEq))]
-#[repr(u16)]
+lazy_static! {
+    static ref AGE: Regex =
+        Regex::new(r#"^[<>]?\s*(?:([0-9]+)y?)?\s*(?:([0-9]+)m)?\s*(?:([0-9]+)d)?$"#).unwrap();
+}
+
+fn is_asserted(node: &GedcomTreeNode) -> bool {
+    node.line_value().as_deref().map(str::trim) == Some("Y")
+}
+
+fn event_date_time(date_node: &GedcomTreeNode) -> Option<DateTime> {
+    date_node.child(&GedcomLineTag::Time)?;
+
+    date_node_to_date_time(date_node).ok()
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum FactTypeId {
-    Birth = 405,
-    Name = 100,
+    Adoption,
+    Baptism,
+    BaptismLds,
+    Birth,
+    Christening,
+    Custom(u16),
+    Death,
+    Emigration,
+    Endowment,
+    Event,
+    Immigration,
+    Name,
+    Naturalisation,
+    SealingChild,
+    SealingSpouse,
+}
+
+impl From<u16> for FactTypeId {
+    fn from(code: u16) -> Self {
+        match code {
+            100 => FactTypeId::Name,
+            405 => FactTypeId::Birth,
+            406 => FactTypeId::Death,
+            407 => FactTypeId::Baptism,
+            408 => FactTypeId::Christening,
+            409 => FactTypeId::Adoption,
+            410 => FactTypeId::Emigration,
+            411 => FactTypeId::Immigration,
+            412 => FactTypeId::Naturalisation,
+            413 => FactTypeId::BaptismLds,
+            414 => FactTypeId::Endowment,
+            415 => FactTypeId::SealingChild,
+            416 => FactTypeId::SealingSpouse,
+            1000 => FactTypeId::Event,
+            _ => FactTypeId::Custom(code),
+        }
+    }
+}
+
+impl Serialize for FactTypeId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u16(self.code())
+    }
+}
+
+impl<'de> Deserialize<'de> for FactTypeId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        u16::deserialize(deserializer).map(FactTypeId::from)
+    }
+}
+
+impl FactTypeId {
+    pub fn code(&self) -> u16 {
+        match self {
+            FactTypeId::Adoption => 409,
+            FactTypeId::Baptism => 407,
+            FactTypeId::BaptismLds => 413,
+            FactTypeId::Birth => 405,
+            FactTypeId::Christening => 408,
+            FactTypeId::Custom(code) => *code,
+            FactTypeId::Death => 406,
+            FactTypeId::Emigration => 410,
+            FactTypeId::Endowment => 414,
+            FactTypeId::Event => 1000,
+            FactTypeId::Immigration => 411,
+            FactTypeId::Name => 100,
+            FactTypeId::Naturalisation => 412,
+            FactTypeId::SealingChild => 415,
+            FactTypeId::SealingSpouse => 416,
+        }
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl JsonSchema for FactTypeId {
+    fn schema_name() -> String {
+        String::from("FactTypeId")
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        integer_enum_schema(&[
+            FactTypeId::Adoption.code(),
+            FactTypeId::Baptism.code(),
+            FactTypeId::BaptismLds.code(),
+            FactTypeId::Birth.code(),
+            FactTypeId::Christening.code(),
+            FactTypeId::Death.code(),
+            FactTypeId::Emigration.code(),
+            FactTypeId::Endowment.code(),
+            FactTypeId::Event.code(),
+            FactTypeId::Immigration.code(),
+            FactTypeId::Name.code(),
+            FactTypeId::Naturalisation.code(),
+            FactTypeId::SealingChild.code(),
+            FactTypeId::SealingSpouse.code(),
+        ])
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(untagged)]
+pub enum Fact {
+    Adoption(Adoption),
+    Birth(Birth),
+    Death(Death),
+    Event(Event),
+    Ordinance(Ordinance),
+}
+
+impl<'de> Deserialize<'de> for Fact {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let fact_type_id = value
+            .get("FactTypeId")
+            .cloned()
+            .ok_or_else(|| de::Error::missing_field("FactTypeId"))?;
+
+        let fact = match serde_json::from_value(fact_type_id).map_err(de::Error::custom)? {
+            FactTypeId::Adoption => serde_json::from_value(value).map(Fact::Adoption),
+            FactTypeId::Birth => serde_json::from_value(value).map(Fact::Birth),
+            FactTypeId::Death => serde_json::from_value(value).map(Fact::Death),
+            FactTypeId::BaptismLds
+            | FactTypeId::Endowment
+            | FactTypeId::SealingChild
+            | FactTypeId::SealingSpouse => serde_json::from_value(value).map(Fact::Ordinance),
+            _ => serde_json::from_value(value).map(Fact::Event),
+        };
+
+        fact.map_err(de::Error::custom)
+    }
+}
+
+impl Fact {
+    pub fn fact_type_id(&self) -> FactTypeId {
+        match self {
+            Fact::Adoption(adoption) => adoption.fact_type_id,
+            Fact::Birth(birth) => birth.fact_type_id,
+            Fact::Death(death) => death.fact_type_id,
+            Fact::Event(event) => event.fact_type_id,
+            Fact::Ordinance(ordinance) => ordinance.fact_type_id,
+        }
+    }
+
+    pub fn map_fact_type_id(&mut self, mapper: &dyn FactMapper) {
+        let fact_type_id = FactTypeId::from(mapper.fact_type_code(self.fact_type_id()));
+
+        match self {
+            Fact::Adoption(adoption) => adoption.fact_type_id = fact_type_id,
+            Fact::Birth(birth) => birth.fact_type_id = fact_type_id,
+            Fact::Death(death) => death.fact_type_id = fact_type_id,
+            Fact::Event(event) => event.fact_type_id = fact_type_id,
+            Fact::Ordinance(ordinance) => ordinance.fact_type_id = fact_type_id,
+        }
+    }
+
+    pub fn citations(&self) -> &[Citation] {
+        match self {
+            Fact::Adoption(adoption) => &adoption.citations,
+            Fact::Birth(birth) => &birth.citations,
+            Fact::Death(death) => &death.citations,
+            Fact::Event(event) => &event.citations,
+            Fact::Ordinance(ordinance) => &ordinance.citations,
+        }
+    }
+
+    pub fn date_detail(&self) -> Option<DateDetail> {
+        match self {
+            Fact::Adoption(adoption) => adoption.date_detail,
+            Fact::Birth(birth) => birth.date_detail,
+            Fact::Death(death) => death.date_detail,
+            Fact::Event(event) => event.date_detail,
+            Fact::Ordinance(ordinance) => ordinance.date_detail,
+        }
+    }
+
+    pub fn place(&self) -> Option<&Place> {
+        match self {
+            Fact::Adoption(adoption) => adoption.place.as_ref(),
+            Fact::Birth(birth) => birth.place.as_ref(),
+            Fact::Death(death) => death.place.as_ref(),
+            Fact::Event(event) => event.place.as_ref(),
+            Fact::Ordinance(ordinance) => ordinance.place.as_ref(),
+        }
+    }
+
+    pub fn date_detail_mut(&mut self) -> Option<&mut DateDetail> {
+        match self {
+            Fact::Adoption(adoption) => adoption.date_detail.as_mut(),
+            Fact::Birth(birth) => birth.date_detail.as_mut(),
+            Fact::Death(death) => death.date_detail.as_mut(),
+            Fact::Event(event) => event.date_detail.as_mut(),
+            Fact::Ordinance(ordinance) => ordinance.date_detail.as_mut(),
+        }
+    }
+
+    pub fn place_mut(&mut self) -> Option<&mut Place> {
+        match self {
+            Fact::Adoption(adoption) => adoption.place.as_mut(),
+            Fact::Birth(birth) => birth.place.as_mut(),
+            Fact::Death(death) => death.place.as_mut(),
+            Fact::Event(event) => event.place.as_mut(),
+            Fact::Ordinance(ordinance) => ordinance.place.as_mut(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Fact::Adoption(adoption) => adoption.is_empty(),
+            Fact::Birth(birth) => birth.is_empty(),
+            Fact::Death(death) => death.is_empty(),
+            Fact::Event(event) => event.is_empty(),
+            Fact::Ordinance(ordinance) => ordinance.is_empty(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub enum AdoptedBy {
+    Both,
+    Husband,
+    Wife,
+}
+
+impl FromStr for AdoptedBy {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_uppercase().as_str() {
+            "BOTH" => Ok(AdoptedBy::Both),
+            "HUSB" => Ok(AdoptedBy::Husband),
+            "WIFE" => Ok(AdoptedBy::Wife),
+            _ => Err("Adopted by must be one of BOTH, HUSB or WIFE"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "PascalCase")]
+pub struct Adoption {
+    adopted_by: AdoptedBy,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    citations: Vec<Citation>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    date_detail: Option<DateDetail>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+    date_time: Option<DateTime>,
+    fact_type_id: FactTypeId,
+    #[serde(skip)]
+    family: Option<Xref>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    place: Option<Place>,
+}
+
+impl From<&GedcomTreeNode> for Adoption {
+    fn from(node: &GedcomTreeNode) -> Self {
+        Self::from_node(node, &MonthLocale::ALL)
+    }
+}
+
+impl Adoption {
+    pub fn builder() -> AdoptionBuilder {
+        AdoptionBuilder::new()
+    }
+
+    pub fn from_node(node: &GedcomTreeNode, month_locales: &[MonthLocale]) -> Self {
+        let mut builder = Adoption::builder();
+
+        for child in node.children().iter() {
+            match child.tag() {
+                GedcomLineTag::Date => {
+                    if let Some(date) = child.line_value().as_ref() {
+                        if let Ok(date_detail) = DateDetail::parse_with_locales(date, month_locales)
+                        {
+                            builder.with_date_detail(date_detail);
+                        }
+                    }
+
+                    if let Some(date_time) = event_date_time(child) {
+                        builder.with_date_time(date_time);
+                    }
+                }
+                GedcomLineTag::FamilyChild => {
+                    if let Some(family) = child.pointer() {
+                        builder.with_family(family);
+                    }
+
+                    let adopted_by = child
+                        .children()
+                        .iter()
+                        .find(|c| c.tag() == &GedcomLineTag::Adoption)
+                        .and_then(|c| c.line_value().as_ref())
+                        .and_then(|v| v.parse().ok());
+
+                    if let Some(adopted_by) = adopted_by {
+                        builder.with_adopted_by(adopted_by);
+                    }
+                }
+                GedcomLineTag::Place if child.line_value().is_some() => {
+                    builder.with_place(Place::from(child));
+                }
+                GedcomLineTag::Source => {
+                    if let Ok(citation) = Citation::try_from(child) {
+                        builder.with_citation(citation);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        builder.build()
+    }
+
+    pub fn adopted_by(&self) -> AdoptedBy {
+        self.adopted_by
+    }
+
+    pub fn citations(&self) -> &[Citation] {
+        &self.citations
+    }
+
+    pub fn date_time(&self) -> Option<DateTime> {
+        self.date_time
+    }
+
+    pub fn family(&self) -> Option<&Xref> {
+        self.family.as_ref()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        let Adoption {
+            adopted_by: _,
+            citations,
+            date_detail,
+            date_time,
+            fact_type_id: _,
+            family,
+            place,
+        } = self;
+
+        citations.is_empty()
+            && date_detail.is_none()
+            && date_time.is_none()
+            && family.is_none()
+            && place.is_none()
+    }
+}
+
+#[derive(Default)]
+pub struct AdoptionBuilder {
+    adopted_by: Option<AdoptedBy>,
+    citations: Vec<Citation>,
+    date_detail: Option<DateDetail>,
+    date_time: Option<DateTime>,
+    family: Option<Xref>,
+    place: Option<Place>,
+}
+
+impl AdoptionBuilder {
+    fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn build(&mut self) -> Adoption {
+        Adoption {
+            adopted_by: self.adopted_by.take().unwrap_or(AdoptedBy::Both),
+            citations: self.citations.drain(..).collect(),
+            date_detail: self.date_detail.take(),
+            date_time: self.date_time.take(),
+            fact_type_id: FactTypeId::Adoption,
+            family: self.family.take(),
+            place: self.place.take(),
+        }
+    }
+
+    pub fn with_adopted_by(&mut self, adopted_by: AdoptedBy) -> &mut Self {
+        self.adopted_by = Some(adopted_by);
+        self
+    }
+
+    pub fn with_citation(&mut self, citation: Citation) -> &mut Self {
+        self.citations.push(citation);
+        self
+    }
+
+    pub fn with_date_detail(&mut self, date_detail: DateDetail) -> &mut Self {
+        self.date_detail = Some(date_detail);
+        self
+    }
+
+    pub fn with_date_detail_from_str(&mut self, date_detail: &str) -> &mut Self {
+        if let Ok(date_detail) = date_detail.parse() {
+            self.date_detail = Some(date_detail);
+        }
+        self
+    }
+
+    pub fn with_date_time(&mut self, date_time: DateTime) -> &mut Self {
+        self.date_time = Some(date_time);
+        self
+    }
+
+    pub fn with_family(&mut self, family: Xref) -> &mut Self {
+        self.family = Some(family);
+        self
+    }
+
+    pub fn with_place(&mut self, place: Place) -> &mut Self {
+        self.place = Some(place);
+        self
+    }
 }
 
-#[derive(Deserialize, Serialize)]
-#[cfg_attr(test, derive(Debug, Eq, PartialEq))]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[serde(rename_all = "PascalCase")]
 pub struct Birth {
-    #[serde(
-        default,
-        deserialize_with = "Birth::deserialize_opt_date_detail",
-        serialize_with = "Birth::serialize_date_detail",
-        skip_serializing_if = "Option::is_none"
-    )]
-    date_detail: Option<NaiveDate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    asserted: Option<bool>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    citations: Vec<Citation>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    date_detail: Option<DateDetail>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+    date_time: Option<DateTime>,
     fact_type_id: FactTypeId,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     place: Option<Place>,
     preferred: bool,
 }
 
 impl From<&GedcomTreeNode> for Birth {
     fn from(node: &GedcomTreeNode) -> Self {
+        Self::from_node(node, &MonthLocale::ALL)
+    }
+}
+
+impl Birth {
+    pub fn builder() -> BirthBuilder {
+        BirthBuilder::new()
+    }
+
+    pub fn from_node(node: &GedcomTreeNode, month_locales: &[MonthLocale]) -> Self {
         let mut builder = Birth::builder();
 
+        if is_asserted(node) {
+            builder.is_asserted();
+        }
+
         for child in node.children().into_iter() {
             let tag = child.tag().clone();
 
@@ -52,13 +511,22 @@ impl From<&GedcomTreeNode> for Birth {
                 }
                 GedcomLineTag::Date => {
                     if let Some(date) = child.line_value().as_ref() {
-                        builder.with_date_detail_from_str(date.as_str());
+                        if let Ok(date_detail) = DateDetail::parse_with_locales(date, month_locales)
+                        {
+                            builder.with_date_detail(date_detail);
+                        }
+                    }
+
+                    if let Some(date_time) = event_date_time(child) {
+                        builder.with_date_time(date_time);
                     }
                 }
-                GedcomLineTag::Place => {
-                    if let Some(place) = child.line_value().as_ref() {
-                        let place = Place::new(place.as_str());
-                        builder.with_place(place);
+                GedcomLineTag::Place if child.line_value().is_some() => {
+                    builder.with_place(Place::from(child));
+                }
+                GedcomLineTag::Source => {
+                    if let Ok(citation) = Citation::try_from(child) {
+                        builder.with_citation(citation);
                     }
                 }
                 _ => {}
@@ -67,92 +535,747 @@ impl From<&GedcomTreeNode> for Birth {
 
         builder.build()
     }
-}
 
-impl Birth {
-    pub fn builder() -> BirthBuilder {
-        BirthBuilder::new()
+    pub fn citations(&self) -> &[Citation] {
+        &self.citations
     }
 
-    fn deserialize_opt_date_detail<'de, D>(deserializer: D) -> Result<Option<NaiveDate>, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        #[derive(Deserialize)]
-        struct Wrapper(#[serde(deserialize_with = "Birth::deserialize_date_detail")] NaiveDate);
+    pub fn date_detail(&self) -> Option<DateDetail> {
+        self.date_detail
+    }
 
-        let d = Option::deserialize(deserializer)?;
-        Ok(d.map(|Wrapper(d)| d))
+    pub fn date_time(&self) -> Option<DateTime> {
+        self.date_time
     }
 
-    fn deserialize_date_detail<'de, D>(deserializer: D) -> Result<NaiveDate, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        let s = String::deserialize(deserializer)?;
-        NaiveDate::parse_from_str(&s, DATE_DETAIL_FORMAT).map_err(de::Error::custom)
+    pub fn is_asserted(&self) -> bool {
+        self.asserted.unwrap_or(false)
     }
 
-    fn serialize_date_detail<S>(
-        date_detail: &Option<NaiveDate>,
-        serializer: S,
-    ) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        let s = format!("{}", date_detail.unwrap().format(DATE_DETAIL_FORMAT));
-        serializer.serialize_str(&s)
+    pub fn is_empty(&self) -> bool {
+        let Birth {
+            asserted,
+            citations,
+            date_detail,
+            date_time,
+            fact_type_id: _,
+            place,
+            preferred: _,
+        } = self;
+
+        asserted.is_none()
+            && citations.is_empty()
+            && date_detail.is_none()
+            && date_time.is_none()
+            && place.is_none()
+    }
+
+    pub fn is_preferred(&self) -> bool {
+        self.preferred
+    }
+
+    pub fn place(&self) -> Option<&Place> {
+        self.place.as_ref()
     }
 }
 
 #[derive(Default)]
 pub struct BirthBuilder {
-    date_detail: Option<NaiveDate>,
+    asserted: Option<bool>,
+    citations: Vec<Citation>,
+    date_detail: Option<DateDetail>,
+    date_time: Option<DateTime>,
+    place: Option<Place>,
+    preferred: Option<bool>,
+}
+
+impl BirthBuilder {
+    fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn build(&mut self) -> Birth {
+        Birth {
+            asserted: self.asserted.take(),
+            citations: self.citations.drain(..).collect(),
+            date_detail: self.date_detail.take(),
+            date_time: self.date_time.take(),
+            fact_type_id: FactTypeId::Birth,
+            place: self.place.take(),
+            preferred: self.preferred.unwrap_or(false),
+        }
+    }
+
+    pub fn is_asserted(&mut self) -> &mut Self {
+        self.asserted = Some(true);
+        self
+    }
+
+    pub fn is_preferred(&mut self) -> &mut Self {
+        self.preferred = Some(true);
+        self
+    }
+
+    pub fn with_citation(&mut self, citation: Citation) -> &mut Self {
+        self.citations.push(citation);
+        self
+    }
+
+    pub fn with_date_detail(&mut self, date_detail: DateDetail) -> &mut Self {
+        self.date_detail = Some(date_detail);
+        self
+    }
+
+    pub fn with_date_detail_from_str(&mut self, date_detail: &str) -> &mut Self {
+        match date_detail.parse() {
+            Ok(date_detail) => {
+                self.date_detail = Some(date_detail);
+                self
+            }
+            Err(_) => self,
+        }
+    }
+
+    pub fn with_date_time(&mut self, date_time: DateTime) -> &mut Self {
+        self.date_time = Some(date_time);
+        self
+    }
+
+    pub fn with_place(&mut self, place: Place) -> &mut Self {
+        self.place = Some(place);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "PascalCase")]
+pub struct Death {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    asserted: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    cause: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    citations: Vec<Citation>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    date_detail: Option<DateDetail>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+    date_time: Option<DateTime>,
+    fact_type_id: FactTypeId,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    place: Option<Place>,
+}
+
+impl From<&GedcomTreeNode> for Death {
+    fn from(node: &GedcomTreeNode) -> Self {
+        Self::from_node(node, &MonthLocale::ALL)
+    }
+}
+
+impl Death {
+    pub fn builder() -> DeathBuilder {
+        DeathBuilder::new()
+    }
+
+    pub fn from_node(node: &GedcomTreeNode, month_locales: &[MonthLocale]) -> Self {
+        let mut builder = Death::builder();
+
+        if is_asserted(node) {
+            builder.is_asserted();
+        }
+
+        for child in node.children().iter() {
+            match child.tag() {
+                GedcomLineTag::Cause => {
+                    if let Some(cause) = child.line_value().as_ref() {
+                        builder.with_cause(cause.as_str());
+                    }
+                }
+                GedcomLineTag::Date => {
+                    if let Some(date) = child.line_value().as_ref() {
+                        if let Ok(date_detail) = DateDetail::parse_with_locales(date, month_locales)
+                        {
+                            builder.with_date_detail(date_detail);
+                        }
+                    }
+
+                    if let Some(date_time) = event_date_time(child) {
+                        builder.with_date_time(date_time);
+                    }
+                }
+                GedcomLineTag::Place if child.line_value().is_some() => {
+                    builder.with_place(Place::from(child));
+                }
+                GedcomLineTag::Source => {
+                    if let Ok(citation) = Citation::try_from(child) {
+                        builder.with_citation(citation);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        builder.build()
+    }
+
+    pub fn cause(&self) -> Option<&str> {
+        self.cause.as_deref()
+    }
+
+    pub fn citations(&self) -> &[Citation] {
+        &self.citations
+    }
+
+    pub fn is_asserted(&self) -> bool {
+        self.asserted.unwrap_or(false)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        let Death {
+            asserted,
+            cause,
+            citations,
+            date_detail,
+            date_time,
+            fact_type_id: _,
+            place,
+        } = self;
+
+        asserted.is_none()
+            && cause.is_none()
+            && citations.is_empty()
+            && date_detail.is_none()
+            && date_time.is_none()
+            && place.is_none()
+    }
+
+    pub fn date_detail(&self) -> Option<DateDetail> {
+        self.date_detail
+    }
+
+    pub fn date_time(&self) -> Option<DateTime> {
+        self.date_time
+    }
+
+    pub fn place(&self) -> Option<&Place> {
+        self.place.as_ref()
+    }
+}
+
+#[derive(Default)]
+pub struct DeathBuilder {
+    asserted: Option<bool>,
+    cause: Option<String>,
+    citations: Vec<Citation>,
+    date_detail: Option<DateDetail>,
+    date_time: Option<DateTime>,
+    place: Option<Place>,
+}
+
+impl DeathBuilder {
+    fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn build(&mut self) -> Death {
+        Death {
+            asserted: self.asserted.take(),
+            cause: self.cause.take(),
+            citations: self.citations.drain(..).collect(),
+            date_detail: self.date_detail.take(),
+            date_time: self.date_time.take(),
+            fact_type_id: FactTypeId::Death,
+            place: self.place.take(),
+        }
+    }
+
+    pub fn is_asserted(&mut self) -> &mut Self {
+        self.asserted = Some(true);
+        self
+    }
+
+    pub fn with_cause(&mut self, cause: &str) -> &mut Self {
+        let cause = cause.trim();
+        if !cause.is_empty() {
+            self.cause = Some(cause.to_owned());
+        }
+        self
+    }
+
+    pub fn with_citation(&mut self, citation: Citation) -> &mut Self {
+        self.citations.push(citation);
+        self
+    }
+
+    pub fn with_date_detail(&mut self, date_detail: DateDetail) -> &mut Self {
+        self.date_detail = Some(date_detail);
+        self
+    }
+
+    pub fn with_date_detail_from_str(&mut self, date_detail: &str) -> &mut Self {
+        if let Ok(date_detail) = date_detail.parse() {
+            self.date_detail = Some(date_detail);
+        }
+        self
+    }
+
+    pub fn with_date_time(&mut self, date_time: DateTime) -> &mut Self {
+        self.date_time = Some(date_time);
+        self
+    }
+
+    pub fn with_place(&mut self, place: Place) -> &mut Self {
+        self.place = Some(place);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "PascalCase")]
+pub struct Ordinance {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    citations: Vec<Citation>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    date_detail: Option<DateDetail>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+    date_time: Option<DateTime>,
+    fact_type_id: FactTypeId,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    place: Option<Place>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    status: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    temple: Option<String>,
+}
+
+impl From<&GedcomTreeNode> for Ordinance {
+    fn from(node: &GedcomTreeNode) -> Self {
+        Self::from_node(node, &MonthLocale::ALL)
+    }
+}
+
+impl Ordinance {
+    pub fn builder() -> OrdinanceBuilder {
+        OrdinanceBuilder::new()
+    }
+
+    pub fn from_node(node: &GedcomTreeNode, month_locales: &[MonthLocale]) -> Self {
+        let mut builder = Ordinance::builder();
+
+        match node.tag() {
+            GedcomLineTag::Endowment => builder.with_fact_type_id(FactTypeId::Endowment),
+            GedcomLineTag::SealingChild => builder.with_fact_type_id(FactTypeId::SealingChild),
+            GedcomLineTag::SealingSpouse => builder.with_fact_type_id(FactTypeId::SealingSpouse),
+            _ => builder.with_fact_type_id(FactTypeId::BaptismLds),
+        };
+
+        for child in node.children().iter() {
+            match child.tag() {
+                GedcomLineTag::Date => {
+                    if let Some(date) = child.line_value().as_ref() {
+                        if let Ok(date_detail) = DateDetail::parse_with_locales(date, month_locales)
+                        {
+                            builder.with_date_detail(date_detail);
+                        }
+                    }
+
+                    if let Some(date_time) = event_date_time(child) {
+                        builder.with_date_time(date_time);
+                    }
+                }
+                GedcomLineTag::Place if child.line_value().is_some() => {
+                    builder.with_place(Place::from(child));
+                }
+                GedcomLineTag::Status => {
+                    if let Some(status) = child.line_value().as_ref() {
+                        builder.with_status(status.as_str());
+                    }
+                }
+                GedcomLineTag::Temple => {
+                    if let Some(temple) = child.line_value().as_ref() {
+                        builder.with_temple(temple.as_str());
+                    }
+                }
+                GedcomLineTag::Source => {
+                    if let Ok(citation) = Citation::try_from(child) {
+                        builder.with_citation(citation);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        builder.build()
+    }
+
+    pub fn citations(&self) -> &[Citation] {
+        &self.citations
+    }
+
+    pub fn date_detail(&self) -> Option<DateDetail> {
+        self.date_detail
+    }
+
+    pub fn date_time(&self) -> Option<DateTime> {
+        self.date_time
+    }
+
+    pub fn is_empty(&self) -> bool {
+        let Ordinance {
+            citations,
+            date_detail,
+            date_time,
+            fact_type_id: _,
+            place,
+            status,
+            temple,
+        } = self;
+
+        citations.is_empty()
+            && date_detail.is_none()
+            && date_time.is_none()
+            && place.is_none()
+            && status.is_none()
+            && temple.is_none()
+    }
+
+    pub fn place(&self) -> Option<&Place> {
+        self.place.as_ref()
+    }
+
+    pub fn status(&self) -> Option<&str> {
+        self.status.as_deref()
+    }
+
+    pub fn temple(&self) -> Option<&str> {
+        self.temple.as_deref()
+    }
+}
+
+#[derive(Default)]
+pub struct OrdinanceBuilder {
+    citations: Vec<Citation>,
+    date_detail: Option<DateDetail>,
+    date_time: Option<DateTime>,
+    fact_type_id: Option<FactTypeId>,
+    place: Option<Place>,
+    status: Option<String>,
+    temple: Option<String>,
+}
+
+impl OrdinanceBuilder {
+    fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn build(&mut self) -> Ordinance {
+        Ordinance {
+            citations: self.citations.drain(..).collect(),
+            date_detail: self.date_detail.take(),
+            date_time: self.date_time.take(),
+            fact_type_id: self.fact_type_id.take().unwrap_or(FactTypeId::BaptismLds),
+            place: self.place.take(),
+            status: self.status.take(),
+            temple: self.temple.take(),
+        }
+    }
+
+    pub fn with_citation(&mut self, citation: Citation) -> &mut Self {
+        self.citations.push(citation);
+        self
+    }
+
+    pub fn with_date_detail(&mut self, date_detail: DateDetail) -> &mut Self {
+        self.date_detail = Some(date_detail);
+        self
+    }
+
+    pub fn with_date_detail_from_str(&mut self, date_detail: &str) -> &mut Self {
+        if let Ok(date_detail) = date_detail.parse() {
+            self.date_detail = Some(date_detail);
+        }
+        self
+    }
+
+    pub fn with_date_time(&mut self, date_time: DateTime) -> &mut Self {
+        self.date_time = Some(date_time);
+        self
+    }
+
+    pub fn with_fact_type_id(&mut self, fact_type_id: FactTypeId) -> &mut Self {
+        self.fact_type_id = Some(fact_type_id);
+        self
+    }
+
+    pub fn with_place(&mut self, place: Place) -> &mut Self {
+        self.place = Some(place);
+        self
+    }
+
+    pub fn with_status(&mut self, status: &str) -> &mut Self {
+        let status = status.trim();
+        if !status.is_empty() {
+            self.status = Some(status.to_uppercase());
+        }
+        self
+    }
+
+    pub fn with_temple(&mut self, temple: &str) -> &mut Self {
+        let temple = temple.trim();
+        if !temple.is_empty() {
+            self.temple = Some(temple.to_uppercase());
+        }
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "PascalCase")]
+pub struct Event {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    age: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    age_in_years: Option<u32>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    citations: Vec<Citation>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    date_detail: Option<DateDetail>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+    date_time: Option<DateTime>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    event_type: Option<String>,
+    fact_type_id: FactTypeId,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    place: Option<Place>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    quality: Option<u8>,
+}
+
+impl From<&GedcomTreeNode> for Event {
+    fn from(node: &GedcomTreeNode) -> Self {
+        Self::from_node(node, &MonthLocale::ALL)
+    }
+}
+
+impl Event {
+    pub fn builder() -> EventBuilder {
+        EventBuilder::new()
+    }
+
+    pub fn from_node(node: &GedcomTreeNode, month_locales: &[MonthLocale]) -> Self {
+        let mut builder = Event::builder();
+
+        match node.tag() {
+            GedcomLineTag::Baptism => builder.with_fact_type_id(FactTypeId::Baptism),
+            GedcomLineTag::Christening => builder.with_fact_type_id(FactTypeId::Christening),
+            GedcomLineTag::Emigration => builder.with_fact_type_id(FactTypeId::Emigration),
+            GedcomLineTag::Immigration => builder.with_fact_type_id(FactTypeId::Immigration),
+            GedcomLineTag::Naturalisation => builder.with_fact_type_id(FactTypeId::Naturalisation),
+            _ => builder.with_fact_type_id(FactTypeId::Event),
+        };
+
+        for child in node.children().iter() {
+            match child.tag() {
+                GedcomLineTag::Age => {
+                    if let Some(age) = child.line_value().as_ref() {
+                        builder.with_age(age.as_str());
+                    }
+                }
+                GedcomLineTag::Date => {
+                    if let Some(date) = child.line_value().as_ref() {
+                        if let Ok(date_detail) = DateDetail::parse_with_locales(date, month_locales)
+                        {
+                            builder.with_date_detail(date_detail);
+                        }
+                    }
+
+                    if let Some(date_time) = event_date_time(child) {
+                        builder.with_date_time(date_time);
+                    }
+                }
+                GedcomLineTag::Place if child.line_value().is_some() => {
+                    builder.with_place(Place::from(child));
+                }
+                GedcomLineTag::QualityOfData => {
+                    if let Some(quality) = child.line_value().as_ref() {
+                        builder.with_quality_from_str(quality.as_str());
+                    }
+                }
+                GedcomLineTag::Type => {
+                    if let Some(event_type) = child.line_value().as_ref() {
+                        builder.with_event_type(event_type.as_str());
+                    }
+                }
+                GedcomLineTag::Source => {
+                    if let Ok(citation) = Citation::try_from(child) {
+                        builder.with_citation(citation);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        builder.build()
+    }
+
+    pub fn age(&self) -> Option<&str> {
+        self.age.as_deref()
+    }
+
+    pub fn age_in_years(&self) -> Option<u32> {
+        self.age_in_years
+    }
+
+    pub fn citations(&self) -> &[Citation] {
+        &self.citations
+    }
+
+    pub fn date_time(&self) -> Option<DateTime> {
+        self.date_time
+    }
+
+    pub fn event_type(&self) -> Option<&str> {
+        self.event_type.as_deref()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        let Event {
+            age,
+            age_in_years,
+            citations,
+            date_detail,
+            date_time,
+            event_type,
+            fact_type_id: _,
+            place,
+            quality,
+        } = self;
+
+        age.is_none()
+            && age_in_years.is_none()
+            && citations.is_empty()
+            && date_detail.is_none()
+            && date_time.is_none()
+            && event_type.is_none()
+            && place.is_none()
+            && quality.is_none()
+    }
+
+    pub fn quality(&self) -> Option<u8> {
+        self.quality
+    }
+
+    fn parse_age_in_years(age: &str) -> Option<u32> {
+        let captures = AGE.captures(age.trim())?;
+
+        let years = captures.get(1).and_then(|y| y.as_str().parse::<u32>().ok());
+        let months = captures.get(2).and_then(|m| m.as_str().parse::<u32>().ok());
+
+        match (years, months) {
+            (None, None) if captures.get(3).is_none() => None,
+            (years, months) => Some(years.unwrap_or(0) + months.unwrap_or(0) / 12),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct EventBuilder {
+    age: Option<String>,
+    age_in_years: Option<u32>,
+    citations: Vec<Citation>,
+    date_detail: Option<DateDetail>,
+    date_time: Option<DateTime>,
+    event_type: Option<String>,
+    fact_type_id: Option<FactTypeId>,
     place: Option<Place>,
-    preferred: Option<bool>,
+    quality: Option<u8>,
 }
 
-impl BirthBuilder {
+impl EventBuilder {
     fn new() -> Self {
         Default::default()
     }
 
-    pub fn build(&mut self) -> Birth {
-        Birth {
+    pub fn build(&mut self) -> Event {
+        Event {
+            age: self.age.take(),
+            age_in_years: self.age_in_years.take(),
+            citations: self.citations.drain(..).collect(),
             date_detail: self.date_detail.take(),
-            fact_type_id: FactTypeId::Birth,
+            date_time: self.date_time.take(),
+            event_type: self.event_type.take(),
+            fact_type_id: self.fact_type_id.take().unwrap_or(FactTypeId::Event),
             place: self.place.take(),
-            preferred: self.preferred.unwrap(),
+            quality: self.quality.take(),
         }
     }
 
-    pub fn is_preferred(&mut self) -> &mut Self {
-        self.preferred = Some(true);
+    pub fn with_citation(&mut self, citation: Citation) -> &mut Self {
+        self.citations.push(citation);
+        self
+    }
+
+    pub fn with_date_detail(&mut self, date_detail: DateDetail) -> &mut Self {
+        self.date_detail = Some(date_detail);
         self
     }
 
     pub fn with_date_detail_from_str(&mut self, date_detail: &str) -> &mut Self {
-        match NaiveDate::parse_from_str(date_detail, DATE_DETAIL_FORMAT) {
-            Ok(date_detail) => {
-                self.date_detail = Some(date_detail);
-                self
-            }
-            Err(_) => self,
+        if let Ok(date_detail) = date_detail.parse() {
+            self.date_detail = Some(date_detail);
         }
+        self
+    }
+
+    pub fn with_date_time(&mut self, date_time: DateTime) -> &mut Self {
+        self.date_time = Some(date_time);
+        self
+    }
+
+    pub fn with_age(&mut self, age: &str) -> &mut Self {
+        self.age = Some(age.to_owned());
+        self.age_in_years = Event::parse_age_in_years(age);
+        self
+    }
+
+    pub fn with_event_type(&mut self, event_type: &str) -> &mut Self {
+        self.event_type = Some(event_type.to_owned());
+        self
+    }
+
+    pub fn with_fact_type_id(&mut self, fact_type_id: FactTypeId) -> &mut Self {
+        self.fact_type_id = Some(fact_type_id);
+        self
     }
 
     pub fn with_place(&mut self, place: Place) -> &mut Self {
         self.place = Some(place);
         self
     }
+
+    pub fn with_quality_from_str(&mut self, quality: &str) -> &mut Self {
+        self.quality = quality.trim().parse().ok().filter(|q| *q <= MAX_QUALITY);
+        self
+    }
 }
 
-#[derive(Deserialize, Serialize)]
-#[cfg_attr(test, derive(Debug, Eq, PartialEq))]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[serde(rename_all = "PascalCase")]
 pub struct Name {
     fact_type_id: FactTypeId,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     given_names: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    suffix: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     surnames: Option<String>,
 }
 
@@ -160,22 +1283,50 @@ impl From<&GedcomTreeNode> for Name {
     fn from(node: &GedcomTreeNode) -> Self {
         let mut builder = Self::builder();
 
-        for child in node.children().into_iter() {
-            match child.tag() {
-                &GedcomLineTag::GivenName => {
-                    if let Some(given_names) = child.line_value().as_ref() {
-                        builder.with_given_names(given_names.as_str());
-                    }
-                }
-                &GedcomLineTag::Surname => {
-                    if let Some(surnames) = child.line_value().as_ref() {
-                        builder.with_surnames(surnames.as_str());
-                    }
-                }
-                _ => {}
+        if let Some(value) = node.line_value().as_ref() {
+            let mut parts = value.splitn(3, '/').map(str::trim);
+
+            if let Some(given_names) = parts.next().filter(|v| !v.is_empty()) {
+                builder.with_given_names(given_names);
+            }
+
+            if let Some(surnames) = parts.next().filter(|v| !v.is_empty()) {
+                builder.with_surnames(surnames);
+            }
+
+            if let Some(suffix) = parts.next().filter(|v| !v.is_empty()) {
+                builder.with_suffix(suffix);
             }
         }
 
+        if let Some(given_names) = node.child_value(&GedcomLineTag::GivenName) {
+            builder.with_given_names(given_names);
+        }
+
+        if let Some(language) = node.child_value(&GedcomLineTag::Language) {
+            builder.with_language(language);
+        }
+
+        if let Some(suffix) = node.child_value(&GedcomLineTag::NameSuffix) {
+            builder.with_suffix(suffix);
+        }
+
+        let surnames: Vec<&str> = node
+            .children_with_tag(&GedcomLineTag::Surname)
+            .filter_map(|c| c.line_value().as_deref())
+            .flat_map(|v| v.split(','))
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+            .collect();
+
+        if !surnames.is_empty() {
+            builder.with_surnames(&surnames.join(" "));
+        }
+
+        if let Some(prefix) = node.child_value(&GedcomLineTag::SurnamePrefix) {
+            builder.with_surname_prefix(prefix);
+        }
+
         builder.build()
     }
 }
@@ -184,11 +1335,33 @@ impl Name {
     pub fn builder() -> NameBuilder {
         NameBuilder::new()
     }
+
+    pub fn given_names(&self) -> Option<&str> {
+        self.given_names.as_deref()
+    }
+
+    pub fn language(&self) -> Option<&str> {
+        self.language.as_deref()
+    }
+
+    pub fn map_fact_type_id(&mut self, mapper: &dyn FactMapper) {
+        self.fact_type_id = FactTypeId::from(mapper.fact_type_code(self.fact_type_id));
+    }
+
+    pub fn suffix(&self) -> Option<&str> {
+        self.suffix.as_deref()
+    }
+
+    pub fn surnames(&self) -> Option<&str> {
+        self.surnames.as_deref()
+    }
 }
 
 #[derive(Default)]
 pub struct NameBuilder {
     given_names: Option<String>,
+    language: Option<String>,
+    suffix: Option<String>,
     surnames: Option<String>,
 }
 
@@ -201,6 +1374,8 @@ impl NameBuilder {
         Name {
             fact_type_id: FactTypeId::Name,
             given_names: self.given_names.take(),
+            language: self.language.take(),
+            suffix: self.suffix.take(),
             surnames: self.surnames.take(),
         }
     }
@@ -210,31 +1385,368 @@ impl NameBuilder {
         self
     }
 
+    pub fn with_language(&mut self, language: &str) -> &mut Self {
+        self.language = Some(language.trim().to_owned());
+        self
+    }
+
+    pub fn with_suffix(&mut self, suffix: &str) -> &mut Self {
+        self.suffix = Some(suffix.to_owned());
+        self
+    }
+
+    pub fn with_surname_prefix(&mut self, prefix: &str) -> &mut Self {
+        let prefix = prefix.trim();
+
+        if let Some(surnames) = self.surnames.as_mut() {
+            let prefixed = surnames
+                .to_lowercase()
+                .starts_with(&format!("{} ", prefix.to_lowercase()));
+
+            if !prefix.is_empty() && !prefixed {
+                *surnames = format!("{} {}", prefix, surnames);
+            }
+        }
+        self
+    }
+
     pub fn with_surnames(&mut self, surnames: &str) -> &mut Self {
         self.surnames = Some(surnames.to_owned());
         self
     }
 }
 
-#[derive(Deserialize, Serialize)]
-#[cfg_attr(test, derive(Debug, Eq, PartialEq))]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[serde(rename_all = "PascalCase")]
 pub struct Place {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    components: Vec<PlaceComponent>,
     place_name: String,
 }
 
+impl From<&GedcomTreeNode> for Place {
+    fn from(node: &GedcomTreeNode) -> Self {
+        let place_name = node.line_value().as_ref().map_or("", |v| v.as_str());
+
+        let form = node
+            .children()
+            .iter()
+            .find(|c| c.tag() == &GedcomLineTag::Format)
+            .and_then(|c| c.line_value().as_ref())
+            .map(|v| PlaceForm::from(v.as_str()));
+
+        match form {
+            Some(form) => Place::with_form(place_name, &form),
+            None => Place::new(place_name),
+        }
+    }
+}
+
 impl Place {
     pub fn new(place_name: &str) -> Self {
         Self {
+            components: vec![],
             place_name: String::from(place_name),
         }
     }
+
+    pub fn with_form(place_name: &str, form: &PlaceForm) -> Self {
+        let mut place = Self::new(place_name);
+        place.apply_form(form);
+        place
+    }
+
+    pub fn apply_form(&mut self, form: &PlaceForm) {
+        if self.components.is_empty()
+            && !form.labels.is_empty()
+            && self.place_name.split(',').count() == form.labels.len()
+        {
+            self.components = Self::split(&self.place_name, form);
+        }
+    }
+
+    pub fn components(&self) -> Vec<PlaceComponent> {
+        if self.components.is_empty() {
+            Self::split(&self.place_name, &PlaceForm::default())
+        } else {
+            self.components.clone()
+        }
+    }
+
+    pub fn place_name(&self) -> &str {
+        &self.place_name
+    }
+
+    fn split(place_name: &str, form: &PlaceForm) -> Vec<PlaceComponent> {
+        place_name
+            .split(',')
+            .map(|v| v.trim())
+            .enumerate()
+            .filter(|(_, v)| !v.is_empty())
+            .map(|(i, v)| PlaceComponent {
+                label: form.labels.get(i).cloned(),
+                value: String::from(v),
+            })
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "PascalCase")]
+pub struct PlaceComponent {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    label: Option<String>,
+    value: String,
+}
+
+impl PlaceComponent {
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PlaceForm {
+    labels: Vec<String>,
+}
+
+impl From<&str> for PlaceForm {
+    fn from(value: &str) -> Self {
+        let labels = value.split(',').map(|l| String::from(l.trim())).collect();
+
+        Self { labels }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{Birth, Name, Place};
+    use super::{
+        AdoptedBy, Adoption, Birth, Citation, DateDetail, Death, Event, Fact, FactTypeId, Name,
+        Ordinance, Place, PlaceForm, Xref,
+    };
+    use crate::{
+        models::{date_time::parse_date_created, gedcom::GedcomTree},
+        parser::parse_gedcom,
+    };
     use serde_json;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_adopted_by_from_str() {
+        assert_eq!(AdoptedBy::from_str("BOTH"), Ok(AdoptedBy::Both));
+        assert_eq!(AdoptedBy::from_str("husb"), Ok(AdoptedBy::Husband));
+        assert_eq!(AdoptedBy::from_str("WIFE"), Ok(AdoptedBy::Wife));
+        assert!(AdoptedBy::from_str("NONE").is_err());
+    }
+
+    #[test]
+    fn can_parse_adoption() {
+        let input = "0 @I1@ INDI\n1 ADOP\n2 DATE 1 Jan 1990\n2 FAMC @F2@\n3 ADOP BOTH\n";
+        let (_, lines) = parse_gedcom(input).unwrap();
+        let tree = GedcomTree::from(lines);
+
+        let actual = Adoption::from(&tree.nodes()[0].children()[0]);
+
+        assert_eq!(actual.adopted_by(), AdoptedBy::Both);
+        assert_eq!(actual.family().map(|f| f.id()), Some("F2"));
+
+        let actual = serde_json::json!(actual).to_string();
+        let expected = r#"{"AdoptedBy":"Both","DateDetail":"1 Jan 1990","FactTypeId":409}"#;
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_fact_is_empty() {
+        let empty = Fact::Birth(Birth::builder().is_preferred().build());
+        assert!(empty.is_empty());
+
+        let dated = Fact::Birth(
+            Birth::builder()
+                .is_preferred()
+                .with_date_detail_from_str("1 Jan 1990")
+                .build(),
+        );
+        assert!(!dated.is_empty());
+    }
+
+    #[test]
+    fn test_fact_is_empty_checks_every_field() {
+        let date_detail = DateDetail::from_str("1 Jan 1990").ok();
+        let date_time = parse_date_created("1990-01-01T09:15:00");
+        let place = Some(Place::new("Dundee"));
+
+        let adoption = Adoption::builder().build();
+        let adoptions = vec![
+            Adoption {
+                citations: vec![Citation::new("S1")],
+                ..adoption.clone()
+            },
+            Adoption {
+                date_detail,
+                ..adoption.clone()
+            },
+            Adoption {
+                date_time,
+                ..adoption.clone()
+            },
+            Adoption {
+                family: Xref::from_str("@F1@").ok(),
+                ..adoption.clone()
+            },
+            Adoption {
+                place: place.clone(),
+                ..adoption.clone()
+            },
+        ];
+        assert!(Fact::Adoption(adoption).is_empty());
+        assert!(adoptions.into_iter().all(|a| !Fact::Adoption(a).is_empty()));
+
+        let birth = Birth::builder().is_preferred().build();
+        let births = vec![
+            Birth {
+                asserted: Some(true),
+                ..birth.clone()
+            },
+            Birth {
+                citations: vec![Citation::new("S1")],
+                ..birth.clone()
+            },
+            Birth {
+                date_detail,
+                ..birth.clone()
+            },
+            Birth {
+                date_time,
+                ..birth.clone()
+            },
+            Birth {
+                place: place.clone(),
+                ..birth.clone()
+            },
+        ];
+        assert!(Fact::Birth(birth).is_empty());
+        assert!(births.into_iter().all(|b| !Fact::Birth(b).is_empty()));
+
+        let death = Death::builder().build();
+        let deaths = vec![
+            Death {
+                asserted: Some(true),
+                ..death.clone()
+            },
+            Death {
+                cause: Some(String::from("Fever")),
+                ..death.clone()
+            },
+            Death {
+                citations: vec![Citation::new("S1")],
+                ..death.clone()
+            },
+            Death {
+                date_detail,
+                ..death.clone()
+            },
+            Death {
+                date_time,
+                ..death.clone()
+            },
+            Death {
+                place: place.clone(),
+                ..death.clone()
+            },
+        ];
+        assert!(Fact::Death(death).is_empty());
+        assert!(deaths.into_iter().all(|d| !Fact::Death(d).is_empty()));
+
+        let event = Event::builder()
+            .with_fact_type_id(FactTypeId::Emigration)
+            .build();
+        let events = vec![
+            Event {
+                age: Some(String::from("42y")),
+                ..event.clone()
+            },
+            Event {
+                age_in_years: Some(42),
+                ..event.clone()
+            },
+            Event {
+                citations: vec![Citation::new("S1")],
+                ..event.clone()
+            },
+            Event {
+                date_detail,
+                ..event.clone()
+            },
+            Event {
+                date_time,
+                ..event.clone()
+            },
+            Event {
+                event_type: Some(String::from("Census")),
+                ..event.clone()
+            },
+            Event {
+                place: place.clone(),
+                ..event.clone()
+            },
+            Event {
+                quality: Some(3),
+                ..event.clone()
+            },
+        ];
+        assert!(Fact::Event(event).is_empty());
+        assert!(events.into_iter().all(|e| !Fact::Event(e).is_empty()));
+
+        let ordinance = Ordinance::builder()
+            .with_fact_type_id(FactTypeId::Endowment)
+            .build();
+        let ordinances = vec![
+            Ordinance {
+                citations: vec![Citation::new("S1")],
+                ..ordinance.clone()
+            },
+            Ordinance {
+                date_detail,
+                ..ordinance.clone()
+            },
+            Ordinance {
+                date_time,
+                ..ordinance.clone()
+            },
+            Ordinance {
+                place: place.clone(),
+                ..ordinance.clone()
+            },
+            Ordinance {
+                status: Some(String::from("COMPLETED")),
+                ..ordinance.clone()
+            },
+            Ordinance {
+                temple: Some(String::from("LOGAN")),
+                ..ordinance.clone()
+            },
+        ];
+        assert!(Fact::Ordinance(ordinance).is_empty());
+        assert!(ordinances
+            .into_iter()
+            .all(|o| !Fact::Ordinance(o).is_empty()));
+    }
+
+    #[test]
+    fn can_deserialize_adoption_fact() {
+        let expected = Fact::Adoption(Adoption::builder().with_adopted_by(AdoptedBy::Wife).build());
+
+        let input = r#"{ "AdoptedBy": "Wife", "FactTypeId": 409 }"#;
+        let actual: Fact = serde_json::from_str(input).unwrap();
+
+        assert_eq!(actual, expected);
+    }
 
     #[test]
     fn can_deserialize_birth() {
@@ -256,6 +1768,16 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn can_serialize_birth_without_preferred() {
+        let input = Birth::builder().with_place(Place::new("Dundee")).build();
+
+        let actual = serde_json::json!(input).to_string();
+        let expected = r#"{"FactTypeId":405,"Place":{"PlaceName":"Dundee"},"Preferred":false}"#;
+
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn can_deserialize_birth_with_date_detail() {
         let expected = Birth::builder()
@@ -335,6 +1857,100 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn can_parse_event() {
+        let input =
+            "0 @I1@ INDI\n1 EVEN\n2 TYPE Graduation\n2 DATE 1 Jul 2012\n2 PLAC St Andrews\n";
+        let (_, lines) = parse_gedcom(input).unwrap();
+        let tree = GedcomTree::from(lines);
+
+        let actual = Event::from(&tree.nodes()[0].children()[0]);
+        assert_eq!(actual.event_type(), Some("Graduation"));
+
+        let actual = serde_json::json!(actual).to_string();
+        let expected = r#"{"DateDetail":"1 Jul 2012","EventType":"Graduation","FactTypeId":1000,"Place":{"PlaceName":"St Andrews"}}"#;
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn can_parse_event_with_age() {
+        let input = Event::builder().with_age("45y").build();
+        assert_eq!(input.age(), Some("45y"));
+        assert_eq!(input.age_in_years(), Some(45));
+
+        let actual = serde_json::json!(input).to_string();
+        let expected = r#"{"Age":"45y","AgeInYears":45,"FactTypeId":1000}"#;
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn can_parse_event_with_age_in_years_and_months() {
+        let input = Event::builder().with_age("< 45y 18m").build();
+        assert_eq!(input.age_in_years(), Some(46));
+    }
+
+    #[test]
+    fn can_parse_event_with_age_keyword() {
+        let input = Event::builder().with_age("INFANT").build();
+        assert_eq!(input.age(), Some("INFANT"));
+        assert_eq!(input.age_in_years(), None);
+
+        let actual = serde_json::json!(input).to_string();
+        let expected = r#"{"Age":"INFANT","FactTypeId":1000}"#;
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn can_parse_event_with_quality() {
+        let input = "0 @I1@ INDI\n1 EVEN\n2 TYPE Graduation\n2 QUAY 3\n";
+        let (_, lines) = parse_gedcom(input).unwrap();
+        let tree = GedcomTree::from(lines);
+
+        let actual = Event::from(&tree.nodes()[0].children()[0]);
+        assert_eq!(actual.quality(), Some(3));
+
+        let actual = serde_json::json!(actual).to_string();
+        let expected = r#"{"EventType":"Graduation","FactTypeId":1000,"Quality":3}"#;
+        assert_eq!(actual, expected);
+
+        let actual = Event::builder().with_quality_from_str("4").build();
+        assert_eq!(actual.quality(), None);
+    }
+
+    #[test]
+    fn can_parse_death_with_cause() {
+        let input = "0 @I1@ INDI\n1 DEAT\n2 DATE 3 MAR 1961\n2 PLAC Dundee\n2 CAUS Heart failure\n";
+        let (_, lines) = parse_gedcom(input).unwrap();
+        let tree = GedcomTree::from(lines);
+
+        let actual = Death::from(&tree.nodes()[0].children()[0]);
+        assert_eq!(actual.cause(), Some("Heart failure"));
+
+        let actual = serde_json::json!(actual).to_string();
+        let expected = r#"{"Cause":"Heart failure","DateDetail":"3 Mar 1961","FactTypeId":406,"Place":{"PlaceName":"Dundee"}}"#;
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn can_deserialize_death_fact() {
+        let expected = Fact::Death(Death::builder().with_cause("Heart failure").build());
+
+        let input = r#"{ "Cause": "Heart failure", "FactTypeId": 406 }"#;
+        let actual: Fact = serde_json::from_str(input).unwrap();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn can_deserialize_event_fact() {
+        let expected = Fact::Event(Event::builder().with_event_type("Graduation").build());
+
+        let input = r#"{ "EventType": "Graduation", "FactTypeId": 1000 }"#;
+        let actual: Fact = serde_json::from_str(input).unwrap();
+
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn can_deserialize_name() {
         let expected = Name::builder()
@@ -361,6 +1977,61 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn can_label_place_components_with_form() {
+        let form = PlaceForm::from("City, County, Country");
+        let actual = Place::with_form("Dundee, Angus, Scotland", &form);
+
+        let labels: Vec<_> = actual
+            .components()
+            .iter()
+            .map(|c| c.label().map(String::from))
+            .collect();
+        assert_eq!(
+            labels,
+            vec![
+                Some(String::from("City")),
+                Some(String::from("County")),
+                Some(String::from("Country"))
+            ]
+        );
+
+        let actual = serde_json::json!(actual).to_string();
+        let expected = r#"{"Components":[{"Label":"City","Value":"Dundee"},{"Label":"County","Value":"Angus"},{"Label":"Country","Value":"Scotland"}],"PlaceName":"Dundee, Angus, Scotland"}"#;
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn can_split_place_components_without_form() {
+        let actual = Place::new("Dundee, Angus, Scotland").components();
+
+        assert_eq!(actual.len(), 3);
+        assert_eq!(actual[1].label(), None);
+        assert_eq!(actual[1].value(), "Angus");
+    }
+
+    #[test]
+    fn can_parse_place_with_local_form() {
+        let input = "0 @I1@ INDI\n1 BIRT\n2 PLAC Dundee, Scotland\n3 FORM City, Country\n";
+        let (_, lines) = parse_gedcom(input).unwrap();
+        let tree = GedcomTree::from(lines);
+
+        let birth = &tree.nodes()[0].children()[0];
+        let actual = Place::from(&birth.children()[0]);
+
+        assert_eq!(actual.components()[1].label(), Some("Country"));
+    }
+
+    #[test]
+    fn can_serialize_name_without_surnames() {
+        let input = Name::builder().with_given_names("Gavin").build();
+
+        let actual = serde_json::json!(input).to_string();
+        let expected = r#"{"FactTypeId":100,"GivenNames":"Gavin"}"#;
+
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn can_deserialize_place() {
         let expected = Place::new("Dundee");
@@ -380,4 +2051,154 @@ mod tests {
 
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn can_parse_spouse_sealing_with_status_and_temple() {
+        let input = "0 @F1@ FAM\n1 SLGS\n2 DATE 12 JUN 1890\n2 STAT completed\n2 TEMP SLAKE\n";
+        let (_, lines) = parse_gedcom(input).unwrap();
+        let tree = GedcomTree::from(lines);
+
+        let actual = Ordinance::from(&tree.nodes()[0].children()[0]);
+        assert_eq!(actual.status(), Some("COMPLETED"));
+        assert_eq!(actual.temple(), Some("SLAKE"));
+
+        let fact = Fact::Ordinance(actual);
+        assert_eq!(fact.fact_type_id(), FactTypeId::SealingSpouse);
+
+        let actual = serde_json::json!(fact).to_string();
+        let expected = r#"{"DateDetail":"12 Jun 1890","FactTypeId":416,"Status":"COMPLETED","Temple":"SLAKE"}"#;
+        assert_eq!(actual, expected);
+
+        let deserialized: Fact = serde_json::from_str(&actual).unwrap();
+        assert_eq!(deserialized, fact);
+    }
+
+    #[test]
+    fn can_parse_birth_with_citation() {
+        let input =
+            "0 @I1@ INDI\n1 BIRT\n2 DATE 1 JAN 1990\n2 SOUR @S1@\n3 PAGE Folio 12\n3 QUAY 2\n";
+        let (_, lines) = parse_gedcom(input).unwrap();
+        let tree = GedcomTree::from(lines);
+
+        let actual = Birth::from(&tree.nodes()[0].children()[0]);
+        assert_eq!(actual.citations().len(), 1);
+        assert_eq!(actual.citations()[0].source_id(), "S1");
+        assert_eq!(actual.citations()[0].page(), Some("Folio 12"));
+
+        let actual = serde_json::json!(actual).to_string();
+        let expected = r#"{"Citations":[{"Page":"Folio 12","Quality":2,"SourceId":"S1"}],"DateDetail":"1 Jan 1990","FactTypeId":405,"Preferred":false}"#;
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn can_parse_name_from_slashed_value() {
+        let input = "0 @I1@ INDI\n1 NAME Gavin James /Henderson/ Jr.\n1 NAME /Reed/\n1 NAME Frank /Henderson/\n2 GIVN Francis\n";
+        let (_, lines) = parse_gedcom(input).unwrap();
+        let tree = GedcomTree::from(lines);
+        let names = tree.nodes()[0].children();
+
+        let actual = Name::from(&names[0]);
+        assert_eq!(actual.given_names(), Some("Gavin James"));
+        assert_eq!(actual.surnames(), Some("Henderson"));
+        assert_eq!(actual.suffix(), Some("Jr."));
+
+        let actual = serde_json::json!(actual).to_string();
+        let expected = r#"{"FactTypeId":100,"GivenNames":"Gavin James","Suffix":"Jr.","Surnames":"Henderson"}"#;
+        assert_eq!(actual, expected);
+
+        let actual = Name::from(&names[1]);
+        assert_eq!(actual.given_names(), None);
+        assert_eq!(actual.surnames(), Some("Reed"));
+
+        let actual = Name::from(&names[2]);
+        assert_eq!(actual.given_names(), Some("Francis"));
+        assert_eq!(actual.surnames(), Some("Henderson"));
+    }
+
+    #[test]
+    fn can_parse_name_with_surname_prefix_and_compound_surnames() {
+        let input = "0 @I1@ INDI\n1 NAME Ludwig /Beethoven/\n2 SPFX van\n2 SURN Beethoven\n1 NAME Ludwig /van Beethoven/\n2 SPFX van\n1 NAME Pablo /Ruiz Picasso/\n2 SURN Ruiz\n2 SURN Picasso\n1 NAME Gabriel /Garcia Marquez/\n2 SURN Garcia, Marquez\n";
+        let (_, lines) = parse_gedcom(input).unwrap();
+        let tree = GedcomTree::from(lines);
+        let names = tree.nodes()[0].children();
+
+        let actual = Name::from(&names[0]);
+        assert_eq!(actual.surnames(), Some("van Beethoven"));
+
+        let actual = Name::from(&names[1]);
+        assert_eq!(actual.surnames(), Some("van Beethoven"));
+
+        let actual = Name::from(&names[2]);
+        assert_eq!(actual.surnames(), Some("Ruiz Picasso"));
+
+        let actual = Name::from(&names[3]);
+        assert_eq!(actual.surnames(), Some("Garcia Marquez"));
+    }
+
+    #[test]
+    fn can_parse_birth_with_time() {
+        let input =
+            "0 @I1@ INDI\n1 BIRT\n2 DATE 1 Jan 1990\n3 TIME 09:15:00\n1 DEAT\n2 DATE 3 MAR 1961\n";
+        let (_, lines) = parse_gedcom(input).unwrap();
+        let tree = GedcomTree::from(lines);
+
+        let actual = Birth::from(&tree.nodes()[0].children()[0]);
+        assert_eq!(
+            actual.date_time(),
+            parse_date_created("1990-01-01T09:15:00")
+        );
+
+        let actual = serde_json::json!(actual).to_string();
+        let expected = r#"{"DateDetail":"1 Jan 1990","DateTime":"1990-01-01T09:15:00","FactTypeId":405,"Preferred":false}"#;
+        assert_eq!(actual, expected);
+
+        let actual = Death::from(&tree.nodes()[0].children()[1]);
+        assert_eq!(actual.date_time(), None);
+    }
+
+    #[test]
+    fn can_parse_name_with_language() {
+        let input = "0 @I1@ INDI\n1 NAME Ivan /Petrov/\n2 LANG Russian\n";
+        let (_, lines) = parse_gedcom(input).unwrap();
+        let tree = GedcomTree::from(lines);
+
+        let actual = Name::from(&tree.nodes()[0].children()[0]);
+        assert_eq!(actual.language(), Some("Russian"));
+
+        let actual = serde_json::json!(actual).to_string();
+        let expected =
+            r#"{"FactTypeId":100,"GivenNames":"Ivan","Language":"Russian","Surnames":"Petrov"}"#;
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn can_parse_name_without_closing_slash() {
+        let input = "0 @I1@ INDI\n1 NAME Gavin /Henderson\n";
+        let (_, lines) = parse_gedcom(input).unwrap();
+        let tree = GedcomTree::from(lines);
+
+        let actual = Name::from(&tree.nodes()[0].children()[0]);
+        assert_eq!(actual.given_names(), Some("Gavin"));
+        assert_eq!(actual.surnames(), Some("Henderson"));
+        assert_eq!(actual.suffix(), None);
+    }
+
+    #[test]
+    fn only_labels_place_components_matching_form_length() {
+        let form = PlaceForm::from("City, County, State, Country");
+        let matching = Place::with_form("Boston, Suffolk, Massachusetts, USA", &form);
+        let shorter = Place::with_form("Dundee, Angus, Scotland", &form);
+
+        let actual = matching.components();
+        assert_eq!(actual[2].label(), Some("State"));
+        assert_eq!(actual[3].label(), Some("Country"));
+
+        let actual = shorter.components();
+        assert_eq!(actual.len(), 3);
+        assert!(actual.iter().all(|c| c.label().is_none()));
+
+        let actual = serde_json::json!(shorter).to_string();
+        let expected = r#"{"PlaceName":"Dundee, Angus, Scotland"}"#;
+        assert_eq!(actual, expected);
+    }
 }
@@ -0,0 +1,39 @@
+use crate::models::relation::FactTypeId;
+use std::{fmt, ptr};
+
+pub trait FactMapper: fmt::Debug + Send + Sync {
+    fn fact_type_code(&self, fact_type_id: FactTypeId) -> u16 {
+        fact_type_id.code()
+    }
+}
+
+impl PartialEq for dyn FactMapper {
+    fn eq(&self, other: &Self) -> bool {
+        ptr::eq(
+            self as *const Self as *const u8,
+            other as *const Self as *const u8,
+        )
+    }
+}
+
+impl Eq for dyn FactMapper {}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct DefaultFactMapper;
+
+impl FactMapper for DefaultFactMapper {}
+
+#[cfg(test)]
+mod tests {
+    use super::{DefaultFactMapper, FactMapper};
+    use crate::models::relation::FactTypeId;
+
+    #[test]
+    fn default_mapper_keeps_fact_type_codes() {
+        let mapper = DefaultFactMapper;
+
+        assert_eq!(mapper.fact_type_code(FactTypeId::Birth), 405);
+        assert_eq!(mapper.fact_type_code(FactTypeId::Name), 100);
+        assert_eq!(mapper.fact_type_code(FactTypeId::Custom(9001)), 9001);
+    }
+}
@@ -1,15 +1,65 @@
-use chrono::NaiveDateTime;
+#[cfg(feature = "schemars")]
+use crate::models::relation::schema::integer_enum_schema;
+use crate::models::{
+    relation::{AdoptedBy, Fact, FactMapper, Restriction},
+    DateTime, ModelError,
+};
+#[cfg(feature = "schemars")]
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
 use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+use std::{collections::BTreeMap, str::FromStr};
 
-#[derive(Clone, Deserialize, Serialize)]
-#[cfg_attr(test, derive(Debug, Eq, PartialEq))]
+#[derive(Clone, Copy, Debug, Deserialize_repr, Eq, PartialEq, Serialize_repr)]
+#[repr(u8)]
+pub enum ChildRelationship {
+    Biological = 1,
+    Adopted = 2,
+    Step = 3,
+    Foster = 4,
+}
+
+#[cfg(feature = "schemars")]
+impl JsonSchema for ChildRelationship {
+    fn schema_name() -> String {
+        String::from("ChildRelationship")
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        integer_enum_schema(&[
+            ChildRelationship::Biological as u16,
+            ChildRelationship::Adopted as u16,
+            ChildRelationship::Step as u16,
+            ChildRelationship::Foster as u16,
+        ])
+    }
+}
+
+impl FromStr for ChildRelationship {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_lowercase().as_str() {
+            "adopted" => Ok(ChildRelationship::Adopted),
+            "biological" | "birth" | "natural" => Ok(ChildRelationship::Biological),
+            "foster" => Ok(ChildRelationship::Foster),
+            "step" => Ok(ChildRelationship::Step),
+            _ => Err(
+                "Child relationship must be one of adopted, biological, birth, foster, natural or step",
+            ),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[serde(rename_all = "PascalCase")]
 pub struct Child {
     child_id: u32,
     family_id: u32,
     id: u32,
-    relationship_to_father: u32,
-    relationship_to_mother: u32,
+    relationship_to_father: ChildRelationship,
+    relationship_to_mother: ChildRelationship,
 }
 
 impl Default for Child {
@@ -18,8 +68,8 @@ impl Default for Child {
             child_id: 0,
             family_id: 100,
             id: 1000,
-            relationship_to_father: 1,
-            relationship_to_mother: 1,
+            relationship_to_father: ChildRelationship::Biological,
+            relationship_to_mother: ChildRelationship::Biological,
         }
     }
 }
@@ -33,16 +83,62 @@ impl Child {
             ..Default::default()
         }
     }
+
+    pub fn adopted_by(&mut self, adopted_by: AdoptedBy) {
+        match adopted_by {
+            AdoptedBy::Both => {
+                self.relationship_to_father = ChildRelationship::Adopted;
+                self.relationship_to_mother = ChildRelationship::Adopted;
+            }
+            AdoptedBy::Husband => self.relationship_to_father = ChildRelationship::Adopted,
+            AdoptedBy::Wife => self.relationship_to_mother = ChildRelationship::Adopted,
+        }
+    }
+
+    pub fn child_id(&self) -> u32 {
+        self.child_id
+    }
+
+    pub fn relationship_to_father(&self) -> ChildRelationship {
+        self.relationship_to_father
+    }
+
+    pub fn relationship_to_mother(&self) -> ChildRelationship {
+        self.relationship_to_mother
+    }
+
+    pub fn family_id(&self) -> u32 {
+        self.family_id
+    }
+
+    pub fn with_relationship_to_father(&mut self, relationship: ChildRelationship) -> &mut Self {
+        self.relationship_to_father = relationship;
+        self
+    }
+
+    pub fn with_relationship_to_mother(&mut self, relationship: ChildRelationship) -> &mut Self {
+        self.relationship_to_mother = relationship;
+        self
+    }
 }
 
-#[derive(Clone, Deserialize, Serialize)]
-#[cfg_attr(test, derive(Debug, Eq, PartialEq))]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[serde(rename_all = "PascalCase")]
 pub struct Family {
-    date_created: NaiveDateTime,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    custom_fields: BTreeMap<String, String>,
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
+    date_created: DateTime,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    facts: Vec<Fact>,
     father_id: u32,
     id: u32,
     mother_id: u32,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    reference_numbers: Vec<(String, Option<String>)>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    restriction: Option<Restriction>,
 }
 
 impl Family {
@@ -50,17 +146,59 @@ impl Family {
         FamilyBuilder::new()
     }
 
+    pub fn custom_fields(&self) -> &BTreeMap<String, String> {
+        &self.custom_fields
+    }
+
+    pub fn facts(&self) -> &[Fact] {
+        &self.facts
+    }
+
+    pub fn father_id(&self) -> u32 {
+        self.father_id
+    }
+
     pub fn id(&self) -> u32 {
         self.id
     }
+
+    pub fn map_fact_types(&mut self, mapper: &dyn FactMapper) {
+        for fact in self.facts.iter_mut() {
+            fact.map_fact_type_id(mapper);
+        }
+    }
+
+    pub fn mother_id(&self) -> u32 {
+        self.mother_id
+    }
+
+    pub fn reference_numbers(&self) -> &[(String, Option<String>)] {
+        &self.reference_numbers
+    }
+
+    pub fn restriction(&self) -> Option<Restriction> {
+        self.restriction
+    }
+
+    pub fn use_iso_dates(&mut self) {
+        for fact in self.facts.iter_mut() {
+            if let Some(date_detail) = fact.date_detail_mut() {
+                date_detail.use_iso();
+            }
+        }
+    }
 }
 
 #[derive(Default)]
 pub struct FamilyBuilder {
-    date_created: Option<NaiveDateTime>,
+    custom_fields: BTreeMap<String, String>,
+    date_created: Option<DateTime>,
+    facts: Vec<Fact>,
     father_id: Option<u32>,
     id: Option<u32>,
     mother_id: Option<u32>,
+    reference_numbers: Vec<(String, Option<String>)>,
+    restriction: Option<Restriction>,
 }
 
 impl FamilyBuilder {
@@ -68,39 +206,69 @@ impl FamilyBuilder {
         Default::default()
     }
 
-    pub fn build(&mut self) -> Result<Family, &str> {
+    pub fn reset(&mut self) {
+        self.custom_fields.clear();
+        self.date_created = None;
+        self.facts.clear();
+        self.father_id = None;
+        self.id = None;
+        self.mother_id = None;
+        self.reference_numbers.clear();
+        self.restriction = None;
+    }
+
+    pub fn build(&mut self) -> Result<Family, ModelError> {
         let date_created = self
             .date_created
             .take()
-            .ok_or("Family must have a date_created specified")?;
+            .ok_or(ModelError::MissingField("date_created"))?;
 
         let father_id = self
             .father_id
             .take()
-            .ok_or("Family must have a father_id specified")?;
+            .ok_or(ModelError::MissingField("father_id"))?;
 
-        let id = self.id.take().ok_or("Family must have an id specified")?;
+        let id = self.id.take().ok_or(ModelError::MissingField("id"))?;
 
         let mother_id = self
             .mother_id
             .take()
-            .ok_or("Family must have a mother_id specified")?;
+            .ok_or(ModelError::MissingField("mother_id"))?;
 
         let family = Family {
+            custom_fields: std::mem::take(&mut self.custom_fields),
             date_created,
+            facts: self.facts.drain(..).collect(),
             father_id,
             id,
             mother_id,
+            reference_numbers: self.reference_numbers.drain(..).collect(),
+            restriction: self.restriction.take(),
         };
 
         Ok(family)
     }
 
-    pub fn with_date_created(&mut self, date_created: NaiveDateTime) -> &mut Self {
+    pub fn with_custom_field(&mut self, tag: &str, value: &str) -> &mut Self {
+        self.custom_fields.insert(tag.to_owned(), value.to_owned());
+        self
+    }
+
+    pub fn with_date_created(&mut self, date_created: DateTime) -> &mut Self {
         self.date_created = Some(date_created);
         self
     }
 
+    pub fn with_default_date_created(&mut self, date_created: DateTime) -> &mut Self {
+        self.date_created.get_or_insert(date_created);
+        self
+    }
+
+    pub fn with_fact(&mut self, fact: Fact) -> &mut Self {
+        self.facts.push(fact);
+        self
+    }
+
     pub fn with_father_id(&mut self, father_id: u32) -> &mut Self {
         self.father_id = Some(father_id);
         self
@@ -115,13 +283,28 @@ impl FamilyBuilder {
         self.mother_id = Some(mother_id);
         self
     }
+
+    pub fn with_reference_number(
+        &mut self,
+        value: &str,
+        reference_type: Option<&str>,
+    ) -> &mut Self {
+        self.reference_numbers
+            .push((value.to_owned(), reference_type.map(|t| t.to_owned())));
+        self
+    }
+
+    pub fn with_restriction(&mut self, restriction: Restriction) -> &mut Self {
+        self.restriction = Some(restriction);
+        self
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{Child, Family};
-    use crate::DATE_CREATED_FORMAT;
-    use chrono::NaiveDateTime;
+    use super::{Child, ChildRelationship, Family};
+    use crate::models::date_time::parse_date_created;
+    use crate::models::relation::AdoptedBy;
     use serde_json;
 
     #[test]
@@ -143,10 +326,29 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn test_from_str_child_relationship() {
+        assert_eq!("Biological".parse(), Ok(ChildRelationship::Biological));
+        assert_eq!("natural".parse(), Ok(ChildRelationship::Biological));
+        assert_eq!("STEP".parse(), Ok(ChildRelationship::Step));
+        assert!("sealing".parse::<ChildRelationship>().is_err());
+    }
+
+    #[test]
+    fn test_child_adopted_by_husband() {
+        let mut child = Child::new(1, 1, 1);
+        child.adopted_by(AdoptedBy::Husband);
+
+        assert_eq!(child.relationship_to_father(), ChildRelationship::Adopted);
+        assert_eq!(
+            child.relationship_to_mother(),
+            ChildRelationship::Biological
+        );
+    }
+
     #[test]
     fn can_deserialize_family() {
-        let date_created =
-            NaiveDateTime::parse_from_str("2020-04-15T16:40:57", DATE_CREATED_FORMAT).unwrap();
+        let date_created = parse_date_created("2020-04-15T16:40:57").unwrap();
         let expected = Family::builder()
             .with_date_created(date_created)
             .with_father_id(1)
@@ -164,8 +366,7 @@ mod tests {
 
     #[test]
     fn can_serialize_family() {
-        let date_created =
-            NaiveDateTime::parse_from_str("2020-04-15T16:40:57", DATE_CREATED_FORMAT).unwrap();
+        let date_created = parse_date_created("2020-04-15T16:40:57").unwrap();
         let input = Family::builder()
             .with_date_created(date_created)
             .with_father_id(1)
@@ -179,4 +380,31 @@ mod tests {
 
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn reset_builder_does_not_leak_state() {
+        let mut builder = Family::builder();
+        builder
+            .with_custom_field("_STAT", "Married")
+            .with_date_created(parse_date_created("2020-04-15T00:00:00").unwrap())
+            .with_father_id(1)
+            .with_id(10_000_001)
+            .with_mother_id(2)
+            .with_reference_number("42", None);
+
+        builder.reset();
+        assert!(builder.build().is_err());
+
+        let actual = builder
+            .with_date_created(parse_date_created("2020-04-16T00:00:00").unwrap())
+            .with_father_id(3)
+            .with_id(10_000_002)
+            .with_mother_id(4)
+            .build()
+            .unwrap();
+
+        let expected =
+            r#"{"DateCreated":"2020-04-16T00:00:00","FatherId":3,"Id":10000002,"MotherId":4}"#;
+        assert_eq!(serde_json::json!(actual).to_string(), expected);
+    }
 }
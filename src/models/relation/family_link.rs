@@ -0,0 +1,143 @@
+use crate::models::{
+    gedcom::{GedcomLineTag, GedcomTreeNode, Xref},
+    relation::ChildRelationship,
+    ModelError,
+};
+use std::{convert::TryFrom, str::FromStr};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FamilyLinkKind {
+    Child,
+    Spouse,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FamilyLinkStatus {
+    Challenged,
+    Disproven,
+    Proven,
+}
+
+impl FromStr for FamilyLinkStatus {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_lowercase().as_str() {
+            "challenged" => Ok(FamilyLinkStatus::Challenged),
+            "disproven" => Ok(FamilyLinkStatus::Disproven),
+            "proven" => Ok(FamilyLinkStatus::Proven),
+            _ => Err("Family link status must be one of challenged, disproven or proven"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FamilyLink {
+    family: Xref,
+    kind: FamilyLinkKind,
+    notes: Vec<String>,
+    pedigree: Option<ChildRelationship>,
+    status: Option<FamilyLinkStatus>,
+}
+
+impl TryFrom<&GedcomTreeNode> for FamilyLink {
+    type Error = ModelError;
+
+    fn try_from(node: &GedcomTreeNode) -> Result<Self, Self::Error> {
+        let kind = match node.tag() {
+            GedcomLineTag::FamilyChild => FamilyLinkKind::Child,
+            GedcomLineTag::FamilySpouse => FamilyLinkKind::Spouse,
+            _ => return Err(ModelError::MissingTag("FAMC")),
+        };
+
+        let family = node.pointer().ok_or(ModelError::MissingValue("family"))?;
+
+        let mut link = Self {
+            family,
+            kind,
+            notes: vec![],
+            pedigree: None,
+            status: None,
+        };
+
+        for child in node.children().iter() {
+            match child.tag() {
+                GedcomLineTag::Note => {
+                    if let Some(note) = child.line_value() {
+                        link.notes.push(note.clone());
+                    }
+                }
+                GedcomLineTag::Pedigree => {
+                    if let Some(pedigree) = child.line_value().as_ref() {
+                        link.pedigree = pedigree.parse().ok();
+                    }
+                }
+                GedcomLineTag::Status => {
+                    if let Some(status) = child.line_value().as_ref() {
+                        link.status = status.parse().ok();
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(link)
+    }
+}
+
+impl FamilyLink {
+    pub fn family(&self) -> &Xref {
+        &self.family
+    }
+
+    pub fn kind(&self) -> FamilyLinkKind {
+        self.kind
+    }
+
+    pub fn notes(&self) -> &[String] {
+        &self.notes
+    }
+
+    pub fn pedigree(&self) -> Option<ChildRelationship> {
+        self.pedigree
+    }
+
+    pub fn status(&self) -> Option<FamilyLinkStatus> {
+        self.status
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FamilyLink, FamilyLinkKind, FamilyLinkStatus};
+    use crate::{
+        models::{gedcom::GedcomTree, relation::ChildRelationship},
+        parser::parse_gedcom,
+    };
+    use std::convert::TryFrom;
+
+    #[test]
+    fn can_parse_family_child_link_with_qualifiers() {
+        let input =
+            "0 @I1@ INDI\n1 FAMC @F1@\n2 PEDI adopted\n2 STAT proven\n2 NOTE Court record\n";
+        let (_, lines) = parse_gedcom(input).unwrap();
+        let tree = GedcomTree::from(lines);
+
+        let actual = FamilyLink::try_from(&tree.nodes()[0].children()[0]).unwrap();
+
+        assert_eq!(actual.family().id(), "F1");
+        assert_eq!(actual.kind(), FamilyLinkKind::Child);
+        assert_eq!(actual.notes(), &[String::from("Court record")]);
+        assert_eq!(actual.pedigree(), Some(ChildRelationship::Adopted));
+        assert_eq!(actual.status(), Some(FamilyLinkStatus::Proven));
+    }
+
+    #[test]
+    fn test_from_str_family_link_status() {
+        assert_eq!("Challenged".parse(), Ok(FamilyLinkStatus::Challenged));
+        assert_eq!(
+            "unknown".parse::<FamilyLinkStatus>(),
+            Err("Family link status must be one of challenged, disproven or proven")
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use crate::models::{
+    gedcom::{date_node_to_date_time_with_format, DateFormat, GedcomLineTag, GedcomTreeNode, Xref},
+    relation::{PlaceForm, Submitter},
+    DateTime,
+};
+
+const SUPPORTED_VERSIONS: [&str; 3] = ["5.5", "5.5.1", "7.0"];
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Header {
+    date: Option<DateTime>,
+    language: Option<String>,
+    place_form: Option<PlaceForm>,
+    source: Option<String>,
+    submitter: Option<Submitter>,
+    submitter_xref: Option<Xref>,
+    version: Option<String>,
+}
+
+impl From<&GedcomTreeNode> for Header {
+    fn from(node: &GedcomTreeNode) -> Self {
+        let mut header = Self::default();
+
+        for child in node.children().iter() {
+            match child.tag() {
+                GedcomLineTag::Gedcom => {
+                    header.version = child
+                        .children()
+                        .iter()
+                        .find(|c| c.tag() == &GedcomLineTag::Version)
+                        .and_then(|c| c.line_value().as_ref())
+                        .map(|v| v.trim().to_owned());
+                }
+                GedcomLineTag::Language => {
+                    header.language = child
+                        .line_value()
+                        .as_ref()
+                        .map(|v| v.trim().to_owned())
+                        .filter(|v| !v.is_empty());
+                }
+                GedcomLineTag::Place => {
+                    header.place_form = child
+                        .children()
+                        .iter()
+                        .find(|c| c.tag() == &GedcomLineTag::Format)
+                        .and_then(|c| c.line_value().as_ref())
+                        .map(|v| PlaceForm::from(v.as_str()));
+                }
+                GedcomLineTag::Source => {
+                    header.source = child
+                        .line_value()
+                        .as_ref()
+                        .map(|v| v.trim().to_owned())
+                        .filter(|v| !v.is_empty());
+                }
+                GedcomLineTag::Submitter => {
+                    header.submitter_xref = child.pointer();
+                }
+                _ => {}
+            }
+        }
+
+        header.date = node
+            .child(&GedcomLineTag::Date)
+            .and_then(|c| date_node_to_date_time_with_format(c, header.date_format()).ok());
+
+        header
+    }
+}
+
+impl Header {
+    pub fn date(&self) -> Option<DateTime> {
+        self.date
+    }
+
+    pub fn date_format(&self) -> DateFormat {
+        DateFormat::for_version(self.version())
+    }
+
+    pub fn language(&self) -> Option<&str> {
+        self.language.as_deref()
+    }
+
+    pub fn place_form(&self) -> Option<&PlaceForm> {
+        self.place_form.as_ref()
+    }
+
+    pub fn source(&self) -> Option<&str> {
+        self.source.as_deref()
+    }
+
+    pub fn submitter(&self) -> Option<&Submitter> {
+        self.submitter.as_ref()
+    }
+
+    pub fn submitter_xref(&self) -> Option<&Xref> {
+        self.submitter_xref.as_ref()
+    }
+
+    pub fn version(&self) -> Option<&str> {
+        self.version.as_deref()
+    }
+
+    pub fn unsupported_version(&self) -> Option<&str> {
+        self.version()
+            .filter(|version| !SUPPORTED_VERSIONS.contains(version) && !version.starts_with("7.0."))
+    }
+
+    pub fn with_submitter(&mut self, submitter: Submitter) -> &mut Self {
+        self.submitter = Some(submitter);
+        self
+    }
+}
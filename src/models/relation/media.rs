@@ -0,0 +1,128 @@
+use crate::models::gedcom::{GedcomLineTag, GedcomTreeNode};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub enum MediaKind {
+    Audio,
+    Document,
+    Image,
+    #[default]
+    Other,
+    Video,
+}
+
+impl From<&str> for MediaKind {
+    fn from(value: &str) -> Self {
+        match value.trim().trim_start_matches('.').to_lowercase().as_str() {
+            "aac" | "flac" | "m4a" | "mp3" | "ogg" | "wav" => MediaKind::Audio,
+            "doc" | "docx" | "odt" | "ole" | "pdf" | "rtf" | "txt" => MediaKind::Document,
+            "bmp" | "gif" | "heic" | "jpeg" | "jpg" | "pcx" | "png" | "tif" | "tiff" | "webp" => {
+                MediaKind::Image
+            }
+            "avi" | "mkv" | "mov" | "mp4" | "mpeg" | "mpg" | "wmv" => MediaKind::Video,
+            _ => MediaKind::Other,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Media {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    file: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    format: Option<String>,
+    kind: MediaKind,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+}
+
+impl From<&GedcomTreeNode> for Media {
+    fn from(node: &GedcomTreeNode) -> Self {
+        let mut media = Self::default();
+        media.read_children(node);
+        media
+    }
+}
+
+impl Media {
+    pub fn file(&self) -> Option<&str> {
+        self.file.as_deref()
+    }
+
+    pub fn format(&self) -> Option<&str> {
+        self.format.as_deref()
+    }
+
+    pub fn kind(&self) -> MediaKind {
+        self.kind
+    }
+
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    fn read_children(&mut self, node: &GedcomTreeNode) {
+        for child in node.children().iter() {
+            let value = child
+                .line_value()
+                .as_ref()
+                .map(|v| v.trim().to_owned())
+                .filter(|v| !v.is_empty());
+
+            match child.tag() {
+                GedcomLineTag::File => {
+                    self.file = value;
+                    self.read_children(child);
+                }
+                GedcomLineTag::Format => {
+                    if let Some(format) = value {
+                        self.kind = MediaKind::from(format.as_str());
+                        self.format = Some(format);
+                    }
+                }
+                GedcomLineTag::Title => self.title = value,
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Media, MediaKind};
+    use crate::{models::gedcom::GedcomTree, parser::parse_gedcom};
+
+    #[test]
+    fn can_parse_image_media() {
+        let input = "0 @O1@ OBJE\n1 FILE photos/gavin.jpg\n2 FORM jpeg\n2 TITL Gavin\n";
+        let (_, lines) = parse_gedcom(input).unwrap();
+        let tree = GedcomTree::from(lines);
+
+        let actual = Media::from(&tree.nodes()[0]);
+
+        assert_eq!(actual.file(), Some("photos/gavin.jpg"));
+        assert_eq!(actual.format(), Some("jpeg"));
+        assert_eq!(actual.kind(), MediaKind::Image);
+        assert_eq!(actual.title(), Some("Gavin"));
+    }
+
+    #[test]
+    fn can_parse_document_media() {
+        let input = "0 @O2@ OBJE\n1 FORM pdf\n1 FILE census.pdf\n";
+        let (_, lines) = parse_gedcom(input).unwrap();
+        let tree = GedcomTree::from(lines);
+
+        let actual = Media::from(&tree.nodes()[0]);
+
+        assert_eq!(actual.format(), Some("pdf"));
+        assert_eq!(actual.kind(), MediaKind::Document);
+    }
+
+    #[test]
+    fn test_media_kind_from_str() {
+        assert_eq!(MediaKind::from("MP3"), MediaKind::Audio);
+        assert_eq!(MediaKind::from("mov"), MediaKind::Video);
+        assert_eq!(MediaKind::from("gedcom"), MediaKind::Other);
+    }
+}
@@ -0,0 +1,304 @@
+use crate::models::relation::{FactMapper, MonthLocale};
+use std::sync::Arc;
+
+const DEFAULT_CHILD_ID_BASE: u32 = 20_000_001;
+const DEFAULT_FAMILY_ID_BASE: u32 = 10_000_001;
+const DEFAULT_ID_STEP: u32 = 1;
+const DEFAULT_PERSON_ID_BASE: u32 = 1;
+const DEFAULT_SUBMITTER_ID_BASE: u32 = 30_000_001;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConversionOptions {
+    child_id_base: u32,
+    custom_fields: bool,
+    fact_mapper: Option<Arc<dyn FactMapper>>,
+    family_id_base: u32,
+    header_date_fallback: bool,
+    id_step: u32,
+    include_raw_lines: bool,
+    include_submitter_as_person: bool,
+    iso_dates: bool,
+    limit: Option<usize>,
+    month_locales: Vec<MonthLocale>,
+    omit_is_living: bool,
+    person_id_base: u32,
+    skip_empty_facts: bool,
+    strict: bool,
+    submitter_id_base: u32,
+    uid_person_ids: bool,
+}
+
+impl Default for ConversionOptions {
+    fn default() -> Self {
+        Self {
+            child_id_base: DEFAULT_CHILD_ID_BASE,
+            custom_fields: false,
+            fact_mapper: None,
+            family_id_base: DEFAULT_FAMILY_ID_BASE,
+            header_date_fallback: false,
+            id_step: DEFAULT_ID_STEP,
+            include_raw_lines: false,
+            include_submitter_as_person: false,
+            iso_dates: false,
+            limit: None,
+            month_locales: MonthLocale::ALL.to_vec(),
+            omit_is_living: false,
+            person_id_base: DEFAULT_PERSON_ID_BASE,
+            skip_empty_facts: false,
+            strict: false,
+            submitter_id_base: DEFAULT_SUBMITTER_ID_BASE,
+            uid_person_ids: false,
+        }
+    }
+}
+
+impl ConversionOptions {
+    pub fn builder() -> ConversionOptionsBuilder {
+        ConversionOptionsBuilder::new()
+    }
+
+    pub fn child_id_base(&self) -> u32 {
+        self.child_id_base
+    }
+
+    pub fn custom_fields(&self) -> bool {
+        self.custom_fields
+    }
+
+    pub fn fact_mapper(&self) -> Option<&dyn FactMapper> {
+        self.fact_mapper.as_deref()
+    }
+
+    pub fn family_id_base(&self) -> u32 {
+        self.family_id_base
+    }
+
+    pub fn header_date_fallback(&self) -> bool {
+        self.header_date_fallback
+    }
+
+    pub fn id_step(&self) -> u32 {
+        self.id_step
+    }
+
+    pub fn include_raw_lines(&self) -> bool {
+        self.include_raw_lines
+    }
+
+    pub fn include_submitter_as_person(&self) -> bool {
+        self.include_submitter_as_person
+    }
+
+    pub fn iso_dates(&self) -> bool {
+        self.iso_dates
+    }
+
+    pub fn limit(&self) -> Option<usize> {
+        self.limit
+    }
+
+    pub fn month_locales(&self) -> &[MonthLocale] {
+        &self.month_locales
+    }
+
+    pub fn omit_is_living(&self) -> bool {
+        self.omit_is_living
+    }
+
+    pub fn person_id_base(&self) -> u32 {
+        self.person_id_base
+    }
+
+    pub fn skip_empty_facts(&self) -> bool {
+        self.skip_empty_facts
+    }
+
+    pub fn strict(&self) -> bool {
+        self.strict
+    }
+
+    pub fn submitter_id_base(&self) -> u32 {
+        self.submitter_id_base
+    }
+
+    pub fn uid_person_ids(&self) -> bool {
+        self.uid_person_ids
+    }
+}
+
+#[derive(Default)]
+pub struct ConversionOptionsBuilder {
+    child_id_base: Option<u32>,
+    custom_fields: Option<bool>,
+    fact_mapper: Option<Arc<dyn FactMapper>>,
+    family_id_base: Option<u32>,
+    header_date_fallback: Option<bool>,
+    id_step: Option<u32>,
+    include_raw_lines: Option<bool>,
+    include_submitter_as_person: Option<bool>,
+    iso_dates: Option<bool>,
+    limit: Option<usize>,
+    month_locales: Option<Vec<MonthLocale>>,
+    omit_is_living: Option<bool>,
+    person_id_base: Option<u32>,
+    skip_empty_facts: Option<bool>,
+    strict: Option<bool>,
+    submitter_id_base: Option<u32>,
+    uid_person_ids: Option<bool>,
+}
+
+impl ConversionOptionsBuilder {
+    fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn build(&mut self) -> ConversionOptions {
+        ConversionOptions {
+            child_id_base: self.child_id_base.take().unwrap_or(DEFAULT_CHILD_ID_BASE),
+            custom_fields: self.custom_fields.take().unwrap_or_default(),
+            fact_mapper: self.fact_mapper.take(),
+            family_id_base: self.family_id_base.take().unwrap_or(DEFAULT_FAMILY_ID_BASE),
+            header_date_fallback: self.header_date_fallback.take().unwrap_or_default(),
+            id_step: self.id_step.take().unwrap_or(DEFAULT_ID_STEP),
+            include_raw_lines: self.include_raw_lines.take().unwrap_or_default(),
+            include_submitter_as_person: self
+                .include_submitter_as_person
+                .take()
+                .unwrap_or_default(),
+            iso_dates: self.iso_dates.take().unwrap_or_default(),
+            limit: self.limit.take(),
+            month_locales: self
+                .month_locales
+                .take()
+                .unwrap_or_else(|| MonthLocale::ALL.to_vec()),
+            omit_is_living: self.omit_is_living.take().unwrap_or_default(),
+            person_id_base: self.person_id_base.take().unwrap_or(DEFAULT_PERSON_ID_BASE),
+            skip_empty_facts: self.skip_empty_facts.take().unwrap_or_default(),
+            strict: self.strict.take().unwrap_or_default(),
+            submitter_id_base: self
+                .submitter_id_base
+                .take()
+                .unwrap_or(DEFAULT_SUBMITTER_ID_BASE),
+            uid_person_ids: self.uid_person_ids.take().unwrap_or_default(),
+        }
+    }
+
+    pub fn custom_fields(&mut self) -> &mut Self {
+        self.custom_fields = Some(true);
+        self
+    }
+
+    pub fn header_date_fallback(&mut self) -> &mut Self {
+        self.header_date_fallback = Some(true);
+        self
+    }
+
+    pub fn include_raw_lines(&mut self) -> &mut Self {
+        self.include_raw_lines = Some(true);
+        self
+    }
+
+    pub fn include_submitter_as_person(&mut self) -> &mut Self {
+        self.include_submitter_as_person = Some(true);
+        self
+    }
+
+    pub fn iso_dates(&mut self) -> &mut Self {
+        self.iso_dates = Some(true);
+        self
+    }
+
+    pub fn omit_is_living(&mut self) -> &mut Self {
+        self.omit_is_living = Some(true);
+        self
+    }
+
+    pub fn skip_empty_facts(&mut self) -> &mut Self {
+        self.skip_empty_facts = Some(true);
+        self
+    }
+
+    pub fn strict(&mut self) -> &mut Self {
+        self.strict = Some(true);
+        self
+    }
+
+    pub fn uid_person_ids(&mut self) -> &mut Self {
+        self.uid_person_ids = Some(true);
+        self
+    }
+
+    pub fn with_child_id_base(&mut self, child_id_base: u32) -> &mut Self {
+        self.child_id_base = Some(child_id_base);
+        self
+    }
+
+    pub fn with_fact_mapper<M: FactMapper + 'static>(&mut self, fact_mapper: M) -> &mut Self {
+        self.fact_mapper = Some(Arc::new(fact_mapper));
+        self
+    }
+
+    pub fn with_family_id_base(&mut self, family_id_base: u32) -> &mut Self {
+        self.family_id_base = Some(family_id_base);
+        self
+    }
+
+    pub fn with_id_step(&mut self, id_step: u32) -> &mut Self {
+        self.id_step = Some(id_step);
+        self
+    }
+
+    pub fn with_limit(&mut self, limit: usize) -> &mut Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn with_month_locales(&mut self, month_locales: &[MonthLocale]) -> &mut Self {
+        self.month_locales = Some(month_locales.to_vec());
+        self
+    }
+
+    pub fn with_person_id_base(&mut self, person_id_base: u32) -> &mut Self {
+        self.person_id_base = Some(person_id_base);
+        self
+    }
+
+    pub fn with_submitter_id_base(&mut self, submitter_id_base: u32) -> &mut Self {
+        self.submitter_id_base = Some(submitter_id_base);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ConversionOptions;
+    use crate::models::relation::MonthLocale;
+
+    #[test]
+    fn test_default_options() {
+        let actual = ConversionOptions::builder().build();
+        assert_eq!(actual, ConversionOptions::default());
+        assert!(!actual.custom_fields());
+        assert!(!actual.header_date_fallback());
+        assert!(!actual.include_raw_lines());
+        assert!(!actual.include_submitter_as_person());
+        assert!(!actual.iso_dates());
+        assert!(!actual.omit_is_living());
+        assert!(!actual.skip_empty_facts());
+        assert!(!actual.strict());
+        assert!(!actual.uid_person_ids());
+        assert_eq!(actual.person_id_base(), 1);
+        assert_eq!(actual.family_id_base(), 10_000_001);
+        assert_eq!(actual.child_id_base(), 20_000_001);
+        assert_eq!(actual.id_step(), 1);
+        assert_eq!(actual.limit(), None);
+        assert_eq!(actual.month_locales(), &MonthLocale::ALL);
+        assert_eq!(actual.submitter_id_base(), 30_000_001);
+    }
+
+    #[test]
+    fn test_skip_empty_facts() {
+        let actual = ConversionOptions::builder().skip_empty_facts().build();
+        assert!(actual.skip_empty_facts());
+    }
+}
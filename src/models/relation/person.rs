@@ -1,27 +1,54 @@
-use crate::{
-    models::{
-        gedcom::{change_node_to_date_time, GedcomLineTag, GedcomTreeNode},
-        relation::{Birth, Name},
+#[cfg(feature = "schemars")]
+use crate::models::relation::schema::integer_enum_schema;
+use crate::models::{
+    date_time::parse_date_created,
+    gedcom::{
+        change_node_to_date_time_with_format, reference_node_to_reference_number, DateFormat,
+        GedcomLineTag, GedcomTreeNode,
     },
-    DATE_CREATED_FORMAT,
+    relation::{
+        Adoption, Birth, Death, Event, Fact, FactMapper, FamilyLink, MonthLocale, Name, Ordinance,
+        PlaceForm, Restriction,
+    },
+    DateTime, ModelError,
 };
-use chrono::NaiveDateTime;
+#[cfg(feature = "schemars")]
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
 use serde::{Deserialize, Serialize};
 use serde_repr::{Deserialize_repr, Serialize_repr};
-use std::convert::TryFrom;
+use std::{collections::BTreeMap, convert::TryFrom, fmt};
+
+const REDACTED_NAME: &str = "Private";
 
-#[derive(Deserialize, Serialize)]
-#[cfg_attr(test, derive(Debug, Eq, PartialEq))]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[serde(rename_all = "PascalCase")]
 pub struct Person {
-    date_created: NaiveDateTime,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    facts: Option<Vec<Birth>>,
+    afn: Option<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    custom_fields: BTreeMap<String, String>,
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
+    date_created: DateTime,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    facts: Vec<Fact>,
+    #[serde(skip)]
+    family_links: Vec<FamilyLink>,
     gender: Gender,
     id: u32,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     is_living: Option<bool>,
     names: Vec<Name>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    reference_numbers: Vec<(String, Option<String>)>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    restriction: Option<Restriction>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    rfn: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    rin: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
 }
 
 impl Person {
@@ -29,72 +56,325 @@ impl Person {
         PersonBuilder::new()
     }
 
+    pub fn afn(&self) -> Option<&str> {
+        self.afn.as_deref()
+    }
+
+    pub fn adoptions(&self) -> impl Iterator<Item = &Adoption> {
+        self.facts.iter().filter_map(|fact| match fact {
+            Fact::Adoption(adoption) => Some(adoption),
+            _ => None,
+        })
+    }
+
+    pub fn birth(&self) -> Option<&Birth> {
+        let births: Vec<&Birth> = self
+            .facts
+            .iter()
+            .filter_map(|fact| match fact {
+                Fact::Birth(birth) => Some(birth),
+                _ => None,
+            })
+            .collect();
+
+        births
+            .iter()
+            .find(|birth| birth.is_preferred())
+            .or_else(|| births.first())
+            .copied()
+    }
+
+    pub fn custom_fields(&self) -> &BTreeMap<String, String> {
+        &self.custom_fields
+    }
+
+    pub fn facts(&self) -> &[Fact] {
+        &self.facts
+    }
+
+    pub fn family_links(&self) -> &[FamilyLink] {
+        &self.family_links
+    }
+
+    pub fn gender(&self) -> Gender {
+        self.gender
+    }
+
     pub fn id(&self) -> u32 {
         self.id
     }
+
+    pub fn is_living(&self) -> Option<bool> {
+        self.is_living
+    }
+
+    pub fn names(&self) -> &[Name] {
+        &self.names
+    }
+
+    pub fn is_private(&self) -> bool {
+        match self.restriction {
+            Some(restriction) => restriction.is_private(),
+            None => false,
+        }
+    }
+
+    pub fn reference_numbers(&self) -> &[(String, Option<String>)] {
+        &self.reference_numbers
+    }
+
+    pub fn restriction(&self) -> Option<Restriction> {
+        self.restriction
+    }
+
+    pub fn rfn(&self) -> Option<&str> {
+        self.rfn.as_deref()
+    }
+
+    pub fn rin(&self) -> Option<&str> {
+        self.rin.as_deref()
+    }
+
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    pub fn apply_place_form(&mut self, form: &PlaceForm) {
+        for fact in self.facts.iter_mut() {
+            if let Some(place) = fact.place_mut() {
+                place.apply_form(form);
+            }
+        }
+    }
+
+    pub fn use_iso_dates(&mut self) {
+        for fact in self.facts.iter_mut() {
+            if let Some(date_detail) = fact.date_detail_mut() {
+                date_detail.use_iso();
+            }
+        }
+    }
+
+    pub fn remove_is_living(&mut self) {
+        self.is_living = None;
+    }
+
+    pub fn remove_custom_fields(&mut self) {
+        self.custom_fields.clear();
+    }
+
+    pub fn map_fact_types(&mut self, mapper: &dyn FactMapper) {
+        for fact in self.facts.iter_mut() {
+            fact.map_fact_type_id(mapper);
+        }
+
+        for name in self.names.iter_mut() {
+            name.map_fact_type_id(mapper);
+        }
+    }
+
+    pub fn remove_empty_facts(&mut self) {
+        self.facts.retain(|fact| !fact.is_empty());
+    }
+
+    pub fn redact(&mut self) {
+        let name = Name::builder().with_given_names(REDACTED_NAME).build();
+        let mut builder = Person::builder();
+
+        builder
+            .with_date_created(self.date_created)
+            .with_gender(Gender::Other)
+            .with_id(self.id)
+            .with_name(name);
+
+        if let Some(restriction) = self.restriction {
+            builder.with_restriction(restriction);
+        }
+
+        if let Ok(mut person) = builder.build() {
+            person.is_living = self.is_living;
+            *self = person;
+        }
+    }
 }
 
 #[derive(Default)]
 pub struct PersonBuilder {
-    date_created: Option<NaiveDateTime>,
-    facts: Option<Vec<Birth>>,
+    afn: Option<String>,
+    custom_fields: BTreeMap<String, String>,
+    date_created: Option<DateTime>,
+    facts: Vec<Fact>,
+    family_links: Vec<FamilyLink>,
     gender: Option<Gender>,
     id: Option<u32>,
     is_living: Option<bool>,
     names: Option<Vec<Name>>,
+    reference_numbers: Vec<(String, Option<String>)>,
+    restriction: Option<Restriction>,
+    rfn: Option<String>,
+    rin: Option<String>,
+    title: Option<String>,
 }
 
 impl TryFrom<&GedcomTreeNode> for PersonBuilder {
-    type Error = &'static str;
+    type Error = ModelError;
 
     fn try_from(node: &GedcomTreeNode) -> Result<Self, Self::Error> {
+        Self::from_node(node, DateFormat::default(), &MonthLocale::ALL)
+    }
+}
+
+impl PersonBuilder {
+    fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn from_node(
+        node: &GedcomTreeNode,
+        date_format: DateFormat,
+        month_locales: &[MonthLocale],
+    ) -> Result<Self, ModelError> {
         let mut builder = Self::new();
+        builder.read_node(node, date_format, month_locales)?;
+        Ok(builder)
+    }
 
+    pub fn read_node(
+        &mut self,
+        node: &GedcomTreeNode,
+        date_format: DateFormat,
+        month_locales: &[MonthLocale],
+    ) -> Result<&mut Self, ModelError> {
         for child in node.children().into_iter() {
             match child.tag() {
+                &GedcomLineTag::Adoption => {
+                    let adoption = Adoption::from_node(child, month_locales);
+                    self.with_adoption(adoption);
+                }
+                &GedcomLineTag::AncestralFileNumber => {
+                    if let Some(afn) = child.line_value().as_ref() {
+                        self.with_afn(afn.as_str());
+                    }
+                }
                 &GedcomLineTag::Birth => {
-                    let birth = Birth::from(child);
-                    builder.with_birth(birth);
+                    let birth = Birth::from_node(child, month_locales);
+                    self.with_birth(birth);
+                }
+                GedcomLineTag::Custom(tag) => {
+                    if let Some(value) = child.line_value().as_deref() {
+                        self.with_custom_field(tag, value);
+                    }
+                }
+                &GedcomLineTag::Death => {
+                    self.not_living()
+                        .with_death(Death::from_node(child, month_locales));
+                }
+                &GedcomLineTag::Baptism
+                | &GedcomLineTag::Christening
+                | &GedcomLineTag::Emigration
+                | &GedcomLineTag::Event
+                | &GedcomLineTag::Immigration
+                | &GedcomLineTag::Naturalisation => {
+                    let event = Event::from_node(child, month_locales);
+                    self.with_event(event);
+                }
+                &GedcomLineTag::BaptismLds
+                | &GedcomLineTag::Endowment
+                | &GedcomLineTag::SealingChild => {
+                    let ordinance = Ordinance::from_node(child, month_locales);
+                    self.with_fact(Fact::Ordinance(ordinance));
                 }
                 &GedcomLineTag::Change => {
-                    let date_created = change_node_to_date_time(child)?;
-                    builder.with_date_created(date_created);
+                    let date_created = change_node_to_date_time_with_format(child, date_format)?;
+                    self.with_date_created(date_created);
+                }
+                &GedcomLineTag::FamilyChild | &GedcomLineTag::FamilySpouse => {
+                    if let Ok(family_link) = FamilyLink::try_from(child) {
+                        self.with_family_link(family_link);
+                    }
                 }
                 &GedcomLineTag::Sex => {
                     let sex = child.line_value().as_ref().map_or("", |v| v.as_str());
                     let gender = Gender::from(sex);
-                    builder.with_gender(gender);
+                    self.with_gender(gender);
                 }
                 &GedcomLineTag::Name => {
                     let name = Name::from(child);
-                    builder.with_name(name);
+                    self.with_name(name);
+                }
+                &GedcomLineTag::Reference => {
+                    if let Ok((value, reference_type)) = reference_node_to_reference_number(child) {
+                        self.with_reference_number(&value, reference_type.as_deref());
+                    }
+                }
+                &GedcomLineTag::RecordFileNumber => {
+                    if let Some(rfn) = child.line_value().as_ref() {
+                        self.with_rfn(rfn.as_str());
+                    }
+                }
+                &GedcomLineTag::RecordIdNumber => {
+                    if let Some(rin) = child.line_value().as_ref() {
+                        self.with_rin(rin.as_str());
+                    }
+                }
+                &GedcomLineTag::Restriction => {
+                    let restriction = child.line_value().as_ref().map_or("", |v| v.as_str());
+                    if let Ok(restriction) = restriction.parse() {
+                        self.with_restriction(restriction);
+                    }
+                }
+                &GedcomLineTag::Title => {
+                    if let Some(title) = child.line_value().as_ref() {
+                        self.with_title(title.as_str());
+                    }
                 }
                 _ => {}
             }
         }
 
-        Ok(builder)
+        Ok(self)
     }
-}
 
-impl PersonBuilder {
-    fn new() -> Self {
-        Default::default()
+    pub fn reset(&mut self) {
+        self.afn = None;
+        self.custom_fields.clear();
+        self.date_created = None;
+        self.facts.clear();
+        self.family_links.clear();
+        self.gender = None;
+        self.id = None;
+        self.is_living = None;
+        self.names = None;
+        self.reference_numbers.clear();
+        self.restriction = None;
+        self.rfn = None;
+        self.rin = None;
+        self.title = None;
     }
 
-    pub fn build(&mut self) -> Result<Person, &'static str> {
-        let date_created = self.date_created.ok_or("Person must have a date_created")?;
-        let gender = self.gender.ok_or("Person must have a gender")?;
-        let id = self.id.ok_or("Person must have an id")?;
+    pub fn build(&mut self) -> Result<Person, ModelError> {
+        let date_created = self
+            .date_created
+            .ok_or(ModelError::MissingField("date_created"))?;
+        let gender = self.gender.ok_or(ModelError::MissingField("gender"))?;
+        let id = self.id.ok_or(ModelError::MissingField("id"))?;
         let names = self.names.take().unwrap_or_default();
 
         let person = Person {
+            afn: self.afn.take(),
+            custom_fields: std::mem::take(&mut self.custom_fields),
             date_created,
             gender,
             id,
             names,
-            facts: self.facts.take(),
-            is_living: Some(true),
+            facts: self.facts.drain(..).collect(),
+            family_links: self.family_links.drain(..).collect(),
+            is_living: Some(self.is_living.take().unwrap_or(true)),
+            reference_numbers: self.reference_numbers.drain(..).collect(),
+            restriction: self.restriction.take(),
+            rfn: self.rfn.take(),
+            rin: self.rin.take(),
+            title: self.title.take(),
         };
 
         Ok(person)
@@ -105,29 +385,67 @@ impl PersonBuilder {
         self
     }
 
+    pub fn not_living(&mut self) -> &mut Self {
+        self.is_living = Some(false);
+        self
+    }
+
+    pub fn with_adoption(&mut self, adoption: Adoption) -> &mut Self {
+        self.with_fact(Fact::Adoption(adoption))
+    }
+
+    pub fn with_afn(&mut self, afn: &str) -> &mut Self {
+        self.afn = Some(afn.trim().to_owned());
+        self
+    }
+
     pub fn with_birth(&mut self, birth: Birth) -> &mut Self {
-        match self.facts {
-            Some(ref mut facts) => facts.push(birth),
-            None => self.facts = Some(vec![birth]),
-        };
+        self.with_fact(Fact::Birth(birth))
+    }
+
+    pub fn with_death(&mut self, death: Death) -> &mut Self {
+        self.with_fact(Fact::Death(death))
+    }
+
+    pub fn with_event(&mut self, event: Event) -> &mut Self {
+        self.with_fact(Fact::Event(event))
+    }
+
+    pub fn with_fact(&mut self, fact: Fact) -> &mut Self {
+        self.facts.push(fact);
+        self
+    }
+
+    pub fn with_custom_field(&mut self, tag: &str, value: &str) -> &mut Self {
+        self.custom_fields.insert(tag.to_owned(), value.to_owned());
         self
     }
 
     pub fn with_date_created_from_str(&mut self, date_created: &str) -> &mut Self {
-        match NaiveDateTime::parse_from_str(date_created, DATE_CREATED_FORMAT) {
-            Ok(date_created) => {
+        match parse_date_created(date_created) {
+            Some(date_created) => {
                 self.date_created = Some(date_created);
                 self
             }
-            Err(_) => self,
+            None => self,
         }
     }
 
-    pub fn with_date_created(&mut self, date_created: NaiveDateTime) -> &mut Self {
+    pub fn with_date_created(&mut self, date_created: DateTime) -> &mut Self {
         self.date_created = Some(date_created);
         self
     }
 
+    pub fn with_default_date_created(&mut self, date_created: DateTime) -> &mut Self {
+        self.date_created.get_or_insert(date_created);
+        self
+    }
+
+    pub fn with_family_link(&mut self, family_link: FamilyLink) -> &mut Self {
+        self.family_links.push(family_link);
+        self
+    }
+
     pub fn with_gender(&mut self, gender: Gender) -> &mut Self {
         self.gender = Some(gender);
         self
@@ -145,6 +463,36 @@ impl PersonBuilder {
         };
         self
     }
+
+    pub fn with_reference_number(
+        &mut self,
+        value: &str,
+        reference_type: Option<&str>,
+    ) -> &mut Self {
+        self.reference_numbers
+            .push((value.to_owned(), reference_type.map(|t| t.to_owned())));
+        self
+    }
+
+    pub fn with_restriction(&mut self, restriction: Restriction) -> &mut Self {
+        self.restriction = Some(restriction);
+        self
+    }
+
+    pub fn with_rfn(&mut self, rfn: &str) -> &mut Self {
+        self.rfn = Some(rfn.trim().to_owned());
+        self
+    }
+
+    pub fn with_rin(&mut self, rin: &str) -> &mut Self {
+        self.rin = Some(rin.trim().to_owned());
+        self
+    }
+
+    pub fn with_title(&mut self, title: &str) -> &mut Self {
+        self.title = Some(title.to_owned());
+        self
+    }
 }
 
 #[derive(Clone, Copy, Debug, Deserialize_repr, Eq, PartialEq, Serialize_repr)]
@@ -155,11 +503,59 @@ pub enum Gender {
     Other = 3,
 }
 
+#[cfg(feature = "schemars")]
+impl JsonSchema for Gender {
+    fn schema_name() -> String {
+        String::from("Gender")
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        integer_enum_schema(&[
+            Gender::Male as u16,
+            Gender::Female as u16,
+            Gender::Other as u16,
+        ])
+    }
+}
+
+impl Gender {
+    pub fn as_gedcom(&self) -> &'static str {
+        match self {
+            Gender::Male => "M",
+            Gender::Female => "F",
+            Gender::Other => "U",
+        }
+    }
+}
+
+impl TryFrom<u8> for Gender {
+    type Error = &'static str;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Gender::Male),
+            2 => Ok(Gender::Female),
+            3 => Ok(Gender::Other),
+            _ => Err("Gender must be one of 1, 2 or 3"),
+        }
+    }
+}
+
+impl fmt::Display for Gender {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Gender::Male => write!(f, "Male"),
+            Gender::Female => write!(f, "Female"),
+            Gender::Other => write!(f, "Other"),
+        }
+    }
+}
+
 impl From<&str> for Gender {
     fn from(value: &str) -> Self {
-        match value.to_lowercase().as_str() {
-            "m" => Gender::Male,
-            "f" => Gender::Female,
+        match value.trim().to_lowercase().as_str() {
+            "m" | "male" => Gender::Male,
+            "f" | "female" => Gender::Female,
             _ => Gender::Other,
         }
     }
@@ -167,9 +563,19 @@ impl From<&str> for Gender {
 
 #[cfg(test)]
 mod tests {
-    use super::{Gender, Person};
-    use crate::models::relation::{Birth, Name, Place};
+    use super::{Gender, Person, PersonBuilder};
+    use crate::{
+        models::{
+            gedcom::GedcomTree,
+            relation::{
+                Birth, ChildRelationship, Death, Fact, FactTypeId, FamilyLinkKind,
+                FamilyLinkStatus, Name, Place,
+            },
+        },
+        parser::parse_gedcom,
+    };
     use serde_json;
+    use std::convert::TryFrom;
 
     #[test]
     fn can_deserialize_person() {
@@ -411,4 +817,297 @@ mod tests {
 
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn can_parse_person_with_reference_numbers() {
+        let input = "0 @I1@ INDI\n1 SEX M\n1 REFN 1234\n1 REFN A-99\n2 TYPE Census\n1 CHAN\n2 DATE 15 APR 2020\n";
+        let (_, lines) = parse_gedcom(input).unwrap();
+        let tree = GedcomTree::from(lines);
+
+        let actual = PersonBuilder::try_from(&tree.nodes()[0])
+            .unwrap()
+            .with_id(1)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            actual.reference_numbers(),
+            &[
+                (String::from("1234"), None),
+                (String::from("A-99"), Some(String::from("Census"))),
+            ]
+        );
+
+        let json = serde_json::json!(actual).to_string();
+        assert!(json.contains(r#""ReferenceNumbers":[["1234",null],["A-99","Census"]]"#));
+    }
+
+    #[test]
+    fn can_parse_person_with_christening() {
+        let input = "0 @I1@ INDI\n1 SEX F\n1 CHR\n2 DATE 4 MAY 1851\n2 PLAC Dundee\n1 CHAN\n2 DATE 15 APR 2020\n";
+        let (_, lines) = parse_gedcom(input).unwrap();
+        let tree = GedcomTree::from(lines);
+
+        let actual = PersonBuilder::try_from(&tree.nodes()[0])
+            .unwrap()
+            .with_id(1)
+            .build()
+            .unwrap();
+
+        let expected = r#"{"DateCreated":"2020-04-15T00:00:00","Facts":[{"DateDetail":"4 May 1851","FactTypeId":408,"Place":{"PlaceName":"Dundee"}}],"Gender":2,"Id":1,"IsLiving":true,"Names":[]}"#;
+        let actual = serde_json::json!(actual).to_string();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn can_parse_person_with_gender_words() {
+        let cases = [
+            ("MALE", Gender::Male),
+            ("Female", Gender::Female),
+            ("m ", Gender::Male),
+            ("U", Gender::Other),
+        ];
+
+        for (sex, expected) in cases.iter() {
+            let input = format!("0 @I1@ INDI\n1 SEX {}\n1 CHAN\n2 DATE 15 APR 2020\n", sex);
+            let (_, lines) = parse_gedcom(&input).unwrap();
+            let tree = GedcomTree::from(lines);
+
+            let actual = PersonBuilder::try_from(&tree.nodes()[0])
+                .unwrap()
+                .with_id(1)
+                .build()
+                .unwrap();
+
+            assert_eq!(actual.gender(), *expected);
+        }
+    }
+
+    #[test]
+    fn can_parse_person_with_family_links() {
+        let input = "0 @I1@ INDI\n1 SEX M\n1 FAMC @F1@\n2 PEDI adopted\n2 STAT proven\n1 FAMS @F2@\n1 CHAN\n2 DATE 15 APR 2020\n";
+        let (_, lines) = parse_gedcom(input).unwrap();
+        let tree = GedcomTree::from(lines);
+
+        let actual = PersonBuilder::try_from(&tree.nodes()[0])
+            .unwrap()
+            .with_id(1)
+            .build()
+            .unwrap();
+
+        let links = actual.family_links();
+        assert_eq!(links.len(), 2);
+        assert_eq!(links[0].family().id(), "F1");
+        assert_eq!(links[0].kind(), FamilyLinkKind::Child);
+        assert_eq!(links[0].pedigree(), Some(ChildRelationship::Adopted));
+        assert_eq!(links[0].status(), Some(FamilyLinkStatus::Proven));
+        assert_eq!(links[1].family().id(), "F2");
+        assert_eq!(links[1].kind(), FamilyLinkKind::Spouse);
+        assert_eq!(links[1].status(), None);
+
+        assert!(!serde_json::json!(actual).to_string().contains("F1"));
+    }
+
+    #[test]
+    fn can_serialize_person_with_birth_and_death_facts() {
+        let birth = Birth::builder()
+            .is_preferred()
+            .with_place(Place::new("Dundee"))
+            .build();
+        let death = Death::builder()
+            .with_cause("Influenza")
+            .with_date_detail_from_str("12 MAR 1918")
+            .build();
+        let name = Name::builder()
+            .with_given_names("Jane")
+            .with_surnames("Reed")
+            .build();
+
+        let input = Person::builder()
+            .with_birth(birth)
+            .with_death(death)
+            .with_date_created_from_str("2020-04-15T16:39:15")
+            .with_gender(Gender::Female)
+            .with_id(1)
+            .with_name(name)
+            .build()
+            .unwrap();
+
+        let fact_type_ids: Vec<FactTypeId> = input.facts().iter().map(Fact::fact_type_id).collect();
+        assert_eq!(fact_type_ids, vec![FactTypeId::Birth, FactTypeId::Death]);
+
+        let expected = r#"{"DateCreated":"2020-04-15T16:39:15","Facts":[{"FactTypeId":405,"Place":{"PlaceName":"Dundee"},"Preferred":true},{"Cause":"Influenza","DateDetail":"12 Mar 1918","FactTypeId":406}],"Gender":2,"Id":1,"IsLiving":true,"Names":[{"FactTypeId":100,"GivenNames":"Jane","Surnames":"Reed"}]}"#;
+        let actual = serde_json::json!(input).to_string();
+
+        assert_eq!(actual, expected);
+
+        let deserialized: Person = serde_json::from_str(&actual).unwrap();
+        assert_eq!(deserialized, input);
+    }
+
+    #[test]
+    fn can_parse_person_with_migration_events() {
+        let input = "0 @I1@ INDI\n1 SEX M\n1 EMIG\n2 PLAC Dundee\n1 IMMI\n2 DATE 3 JUN 1872\n2 PLAC New York\n1 CHAN\n2 DATE 15 APR 2020\n";
+        let (_, lines) = parse_gedcom(input).unwrap();
+        let tree = GedcomTree::from(lines);
+
+        let actual = PersonBuilder::try_from(&tree.nodes()[0])
+            .unwrap()
+            .with_id(1)
+            .build()
+            .unwrap();
+
+        let fact_type_ids: Vec<FactTypeId> =
+            actual.facts().iter().map(Fact::fact_type_id).collect();
+        assert_eq!(
+            fact_type_ids,
+            vec![FactTypeId::Emigration, FactTypeId::Immigration]
+        );
+
+        let expected = r#"{"DateCreated":"2020-04-15T00:00:00","Facts":[{"FactTypeId":410,"Place":{"PlaceName":"Dundee"}},{"DateDetail":"3 Jun 1872","FactTypeId":411,"Place":{"PlaceName":"New York"}}],"Gender":1,"Id":1,"IsLiving":true,"Names":[]}"#;
+        let actual = serde_json::json!(actual).to_string();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn person_facts_preserve_source_order() {
+        let input = "0 @I1@ INDI\n1 SEX F\n1 DEAT\n2 DATE 3 MAR 1961\n1 EVEN\n2 TYPE Graduation\n1 BIRT\n2 DATE 1 JAN 1900\n1 CHAN\n2 DATE 15 APR 2020\n";
+        let (_, lines) = parse_gedcom(input).unwrap();
+        let tree = GedcomTree::from(lines);
+
+        let actual = PersonBuilder::try_from(&tree.nodes()[0])
+            .unwrap()
+            .with_id(1)
+            .build()
+            .unwrap();
+
+        let fact_type_ids: Vec<FactTypeId> =
+            actual.facts().iter().map(Fact::fact_type_id).collect();
+        assert_eq!(
+            fact_type_ids,
+            vec![FactTypeId::Death, FactTypeId::Event, FactTypeId::Birth]
+        );
+
+        let expected = r#"{"DateCreated":"2020-04-15T00:00:00","Facts":[{"DateDetail":"3 Mar 1961","FactTypeId":406},{"EventType":"Graduation","FactTypeId":1000},{"DateDetail":"1 Jan 1900","FactTypeId":405,"Preferred":false}],"Gender":2,"Id":1,"IsLiving":false,"Names":[]}"#;
+        let actual = serde_json::json!(actual).to_string();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn can_parse_person_with_record_identifiers() {
+        let input = "0 @I1@ INDI\n1 SEX M\n1 AFN 1JRT-4TQ\n1 RIN 42\n1 CHAN\n2 DATE 15 APR 2020\n";
+        let (_, lines) = parse_gedcom(input).unwrap();
+        let tree = GedcomTree::from(lines);
+
+        let actual = PersonBuilder::try_from(&tree.nodes()[0])
+            .unwrap()
+            .with_id(1)
+            .build()
+            .unwrap();
+
+        assert_eq!(actual.afn(), Some("1JRT-4TQ"));
+        assert_eq!(actual.rfn(), None);
+        assert_eq!(actual.rin(), Some("42"));
+
+        let expected = r#"{"Afn":"1JRT-4TQ","DateCreated":"2020-04-15T00:00:00","Gender":1,"Id":1,"IsLiving":true,"Names":[],"Rin":"42"}"#;
+        let actual = serde_json::json!(actual).to_string();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn gender_as_gedcom() {
+        assert_eq!(Gender::Male.as_gedcom(), "M");
+        assert_eq!(Gender::Female.as_gedcom(), "F");
+        assert_eq!(Gender::Other.as_gedcom(), "U");
+
+        for gender in [Gender::Male, Gender::Female, Gender::Other].iter() {
+            assert_eq!(Gender::from(gender.as_gedcom()), *gender);
+        }
+    }
+
+    #[test]
+    fn gender_u8_round_trip() {
+        for gender in [Gender::Male, Gender::Female, Gender::Other].iter() {
+            assert_eq!(Gender::try_from(*gender as u8), Ok(*gender));
+        }
+
+        assert_eq!(Gender::try_from(0), Err("Gender must be one of 1, 2 or 3"));
+        assert!(Gender::try_from(4).is_err());
+    }
+
+    #[test]
+    fn reset_builder_does_not_leak_state() {
+        let input = "0 @I1@ INDI\n1 NAME Gavin /Henderson/\n1 SEX M\n1 AFN 1JRT-4TQ\n1 TITL Sir\n1 BIRT\n2 DATE 1 JAN 1990\n1 FAMC @F1@\n1 CHAN\n2 DATE 15 APR 2020\n";
+        let (_, lines) = parse_gedcom(input).unwrap();
+        let tree = GedcomTree::from(lines);
+
+        let mut builder = PersonBuilder::try_from(&tree.nodes()[0]).unwrap();
+        let first = builder.with_id(1).build().unwrap();
+        assert_eq!(first.facts().len(), 1);
+
+        builder.reset();
+        assert!(builder.build().is_err());
+
+        let second = builder
+            .with_date_created_from_str("2020-04-16T00:00:00")
+            .with_gender(Gender::Female)
+            .with_id(2)
+            .build()
+            .unwrap();
+
+        let expected =
+            r#"{"DateCreated":"2020-04-16T00:00:00","Gender":2,"Id":2,"IsLiving":true,"Names":[]}"#;
+        let actual = serde_json::json!(second).to_string();
+
+        assert_eq!(actual, expected);
+        assert!(second.family_links().is_empty());
+    }
+
+    #[test]
+    fn can_parse_person_with_asserted_death() {
+        let input = "0 @I1@ INDI\n1 SEX F\n1 DEAT Y\n1 CHAN\n2 DATE 15 APR 2020\n";
+        let (_, lines) = parse_gedcom(input).unwrap();
+        let tree = GedcomTree::from(lines);
+
+        let mut actual = PersonBuilder::try_from(&tree.nodes()[0])
+            .unwrap()
+            .with_id(1)
+            .build()
+            .unwrap();
+        actual.remove_empty_facts();
+
+        assert_eq!(actual.is_living(), Some(false));
+        assert!(matches!(actual.facts(), [Fact::Death(death)] if death.is_asserted()));
+
+        let expected = r#"{"DateCreated":"2020-04-15T00:00:00","Facts":[{"Asserted":true,"FactTypeId":406}],"Gender":2,"Id":1,"IsLiving":false,"Names":[]}"#;
+        let actual = serde_json::json!(actual).to_string();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn can_parse_person_with_death_and_no_birth() {
+        let input = "0 @I1@ INDI\n1 NAME Jane /Reed/\n1 SEX F\n1 DEAT\n2 DATE 4 MAY 1921\n2 PLAC Dundee\n1 CHAN\n2 DATE 15 APR 2020\n";
+        let (_, lines) = parse_gedcom(input).unwrap();
+        let tree = GedcomTree::from(lines);
+
+        let actual = PersonBuilder::try_from(&tree.nodes()[0])
+            .unwrap()
+            .with_id(1)
+            .build()
+            .unwrap();
+
+        assert!(matches!(actual.facts(), [Fact::Death(_)]));
+        assert_eq!(actual.birth(), None);
+        assert_eq!(actual.is_living(), Some(false));
+
+        let expected = r#"{"DateCreated":"2020-04-15T00:00:00","Facts":[{"DateDetail":"4 May 1921","FactTypeId":406,"Place":{"PlaceName":"Dundee"}}],"Gender":2,"Id":1,"IsLiving":false,"Names":[{"FactTypeId":100,"GivenNames":"Jane","Surnames":"Reed"}]}"#;
+        let actual = serde_json::json!(actual).to_string();
+
+        assert_eq!(actual, expected);
+    }
 }
@@ -0,0 +1,43 @@
+use crate::models::relation::{DateDetail, Fact, FactTypeId};
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "PascalCase")]
+pub struct PersonFact {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    date_detail: Option<DateDetail>,
+    fact_type_id: FactTypeId,
+    person_id: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    place_name: Option<String>,
+}
+
+impl PersonFact {
+    pub fn new(person_id: u32, fact: &Fact) -> Self {
+        Self {
+            date_detail: fact.date_detail(),
+            fact_type_id: fact.fact_type_id(),
+            person_id,
+            place_name: fact.place().map(|p| p.place_name().to_owned()),
+        }
+    }
+
+    pub fn date_detail(&self) -> Option<DateDetail> {
+        self.date_detail
+    }
+
+    pub fn fact_type_id(&self) -> FactTypeId {
+        self.fact_type_id
+    }
+
+    pub fn person_id(&self) -> u32 {
+        self.person_id
+    }
+
+    pub fn place_name(&self) -> Option<&str> {
+        self.place_name.as_deref()
+    }
+}
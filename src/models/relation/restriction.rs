@@ -0,0 +1,71 @@
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub enum Restriction {
+    Confidential,
+    Locked,
+    Privacy,
+}
+
+impl Restriction {
+    pub fn is_private(&self) -> bool {
+        match self {
+            Restriction::Confidential | Restriction::Privacy => true,
+            Restriction::Locked => false,
+        }
+    }
+}
+
+impl FromStr for Restriction {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_lowercase().as_str() {
+            "confidential" => Ok(Restriction::Confidential),
+            "locked" => Ok(Restriction::Locked),
+            "privacy" => Ok(Restriction::Privacy),
+            _ => Err("Restriction must be one of confidential, locked or privacy"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Restriction;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_from_str_confidential() {
+        let actual = Restriction::from_str("confidential");
+        assert_eq!(actual, Ok(Restriction::Confidential));
+    }
+
+    #[test]
+    fn test_from_str_locked_uppercase() {
+        let actual = Restriction::from_str("LOCKED");
+        assert_eq!(actual, Ok(Restriction::Locked));
+    }
+
+    #[test]
+    fn test_from_str_privacy() {
+        let actual = Restriction::from_str("privacy");
+        assert_eq!(actual, Ok(Restriction::Privacy));
+    }
+
+    #[test]
+    fn test_from_str_invalid() {
+        let actual = Restriction::from_str("secret");
+        assert!(actual.is_err());
+    }
+
+    #[test]
+    fn test_is_private() {
+        assert!(Restriction::Confidential.is_private());
+        assert!(Restriction::Privacy.is_private());
+        assert!(!Restriction::Locked.is_private());
+    }
+}
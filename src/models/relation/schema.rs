@@ -0,0 +1,10 @@
+use schemars::schema::{InstanceType, Schema, SchemaObject};
+
+pub(crate) fn integer_enum_schema(values: &[u16]) -> Schema {
+    SchemaObject {
+        enum_values: Some(values.iter().map(|v| (*v).into()).collect()),
+        instance_type: Some(InstanceType::Integer.into()),
+        ..Default::default()
+    }
+    .into()
+}
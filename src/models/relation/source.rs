@@ -0,0 +1,117 @@
+use crate::models::{
+    gedcom::{GedcomLineTag, GedcomTreeNode},
+    ModelError,
+};
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "PascalCase")]
+pub struct Source {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    abbreviation: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    author: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    publication: Option<String>,
+    source_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+}
+
+impl TryFrom<&GedcomTreeNode> for Source {
+    type Error = ModelError;
+
+    fn try_from(node: &GedcomTreeNode) -> Result<Self, Self::Error> {
+        if node.tag() != &GedcomLineTag::Source {
+            return Err(ModelError::MissingTag("SOUR"));
+        }
+
+        let xref = node.xref().ok_or(ModelError::MissingField("xref"))?;
+        let mut source = Source::new(xref.id());
+
+        for child in node.children().iter() {
+            let value = child
+                .line_value()
+                .as_deref()
+                .map(|v| v.trim().to_owned())
+                .filter(|v| !v.is_empty());
+
+            match child.tag() {
+                GedcomLineTag::Abbreviation => source.abbreviation = value,
+                GedcomLineTag::Author => source.author = value,
+                GedcomLineTag::Publication => source.publication = value,
+                GedcomLineTag::Title => source.title = value,
+                _ => {}
+            }
+        }
+
+        Ok(source)
+    }
+}
+
+impl Source {
+    pub fn new(source_id: &str) -> Self {
+        Self {
+            abbreviation: None,
+            author: None,
+            publication: None,
+            source_id: source_id.to_owned(),
+            title: None,
+        }
+    }
+
+    pub fn abbreviation(&self) -> Option<&str> {
+        self.abbreviation.as_deref()
+    }
+
+    pub fn author(&self) -> Option<&str> {
+        self.author.as_deref()
+    }
+
+    pub fn publication(&self) -> Option<&str> {
+        self.publication.as_deref()
+    }
+
+    pub fn source_id(&self) -> &str {
+        &self.source_id
+    }
+
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Source;
+    use crate::{models::gedcom::GedcomTree, parser::parse_gedcom};
+    use std::convert::TryFrom;
+
+    #[test]
+    fn can_parse_source_record() {
+        let input = "0 @S1@ SOUR\n1 TITL Parish register\n1 AUTH Church of Scotland\n1 ABBR OPR\n1 PUBL Dundee, 1850\n";
+        let (_, lines) = parse_gedcom(input).unwrap();
+        let tree = GedcomTree::from(lines);
+
+        let actual = Source::try_from(&tree.nodes()[0]).unwrap();
+
+        assert_eq!(actual.source_id(), "S1");
+        assert_eq!(actual.title(), Some("Parish register"));
+        assert_eq!(actual.author(), Some("Church of Scotland"));
+        assert_eq!(actual.abbreviation(), Some("OPR"));
+        assert_eq!(actual.publication(), Some("Dundee, 1850"));
+    }
+
+    #[test]
+    fn cannot_parse_source_without_xref() {
+        let input = "0 HEAD\n1 SOUR FINDMYPAST\n";
+        let (_, lines) = parse_gedcom(input).unwrap();
+        let tree = GedcomTree::from(lines);
+
+        assert!(Source::try_from(&tree.nodes()[0].children()[0]).is_err());
+    }
+}
@@ -0,0 +1,141 @@
+use crate::models::relation::{Child, Family, Person};
+use std::{collections::HashMap, fmt};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Stats {
+    child_links: usize,
+    families: usize,
+    generations: usize,
+    persons: usize,
+}
+
+impl Stats {
+    pub fn new(childs: &[Child], familys: &[Family], persons: &[Person]) -> Self {
+        let parents: HashMap<u32, &Family> = familys.iter().map(|f| (f.id(), f)).collect();
+        let mut parents_of: HashMap<u32, Vec<u32>> = HashMap::new();
+
+        for child in childs.iter() {
+            if let Some(family) = parents.get(&child.family_id()) {
+                parents_of
+                    .entry(child.child_id())
+                    .or_default()
+                    .extend(&[family.father_id(), family.mother_id()]);
+            }
+        }
+
+        let mut depths: HashMap<u32, usize> = HashMap::new();
+        let generations = persons
+            .iter()
+            .map(|p| Self::depth(p.id(), &parents_of, &mut depths))
+            .max()
+            .unwrap_or(0);
+
+        Self {
+            child_links: childs.len(),
+            families: familys.len(),
+            generations,
+            persons: persons.len(),
+        }
+    }
+
+    pub fn child_links(&self) -> usize {
+        self.child_links
+    }
+
+    pub fn families(&self) -> usize {
+        self.families
+    }
+
+    pub fn generations(&self) -> usize {
+        self.generations
+    }
+
+    pub fn persons(&self) -> usize {
+        self.persons
+    }
+
+    fn depth(
+        person_id: u32,
+        parents_of: &HashMap<u32, Vec<u32>>,
+        depths: &mut HashMap<u32, usize>,
+    ) -> usize {
+        if let Some(depth) = depths.get(&person_id) {
+            return *depth;
+        }
+
+        depths.insert(person_id, 1);
+
+        let depth = match parents_of.get(&person_id) {
+            Some(parent_ids) => {
+                1 + parent_ids
+                    .iter()
+                    .map(|id| Self::depth(*id, parents_of, depths))
+                    .max()
+                    .unwrap_or(0)
+            }
+            None => 1,
+        };
+
+        depths.insert(person_id, depth);
+        depth
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {}, {} {}, {} {}, {} {}",
+            self.persons,
+            if self.persons == 1 {
+                "person"
+            } else {
+                "persons"
+            },
+            self.families,
+            if self.families == 1 {
+                "family"
+            } else {
+                "families"
+            },
+            self.child_links,
+            if self.child_links == 1 {
+                "child link"
+            } else {
+                "child links"
+            },
+            self.generations,
+            if self.generations == 1 {
+                "generation"
+            } else {
+                "generations"
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Stats;
+
+    #[test]
+    fn can_display_stats() {
+        let stats = Stats {
+            child_links: 2,
+            families: 1,
+            generations: 2,
+            persons: 4,
+        };
+
+        let actual = stats.to_string();
+        let expected = "4 persons, 1 family, 2 child links, 2 generations";
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn can_calculate_empty_stats() {
+        let actual = Stats::new(&[], &[], &[]);
+        assert_eq!(actual.generations(), 0);
+        assert_eq!(actual.persons(), 0);
+    }
+}
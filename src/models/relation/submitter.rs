@@ -0,0 +1,114 @@
+use crate::models::{
+    gedcom::{GedcomLineTag, GedcomTreeNode},
+    relation::Address,
+    ModelError,
+};
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+
+const UNKNOWN_NAME: &str = "not known";
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Submitter {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    address: Option<Address>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    phone: Option<String>,
+}
+
+impl TryFrom<&GedcomTreeNode> for Submitter {
+    type Error = ModelError;
+
+    fn try_from(node: &GedcomTreeNode) -> Result<Self, Self::Error> {
+        if node.tag() != &GedcomLineTag::Submitter {
+            return Err(ModelError::MissingTag("SUBM"));
+        }
+
+        let mut submitter = Self::default();
+
+        for child in node.children().iter() {
+            if child.tag() == &GedcomLineTag::Address {
+                submitter.address = Some(Address::from(child));
+                continue;
+            }
+
+            let value = match child.line_value() {
+                Some(value) => value.trim(),
+                None => continue,
+            };
+
+            match child.tag() {
+                GedcomLineTag::Name
+                    if !value.is_empty() && value.to_lowercase() != UNKNOWN_NAME =>
+                {
+                    submitter.name = Some(value.to_owned());
+                }
+                GedcomLineTag::Phone => submitter.phone = Some(value.to_owned()),
+                _ => {}
+            }
+        }
+
+        Ok(submitter)
+    }
+}
+
+impl Submitter {
+    pub fn address(&self) -> Option<&Address> {
+        self.address.as_ref()
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    pub fn phone(&self) -> Option<&str> {
+        self.phone.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Submitter;
+    use crate::{models::gedcom::GedcomTree, parser::parse_gedcom};
+    use std::convert::TryFrom;
+
+    #[test]
+    fn can_parse_submitter() {
+        let input =
+            "0 @SUBM1@ SUBM\n1 NAME Donald Robertson\n1 ADDR 1 High Street\n1 PHON 01382 000000\n";
+        let (_, lines) = parse_gedcom(input).unwrap();
+        let tree = GedcomTree::from(lines);
+
+        let actual = Submitter::try_from(&tree.nodes()[0]).unwrap();
+
+        assert_eq!(actual.name(), Some("Donald Robertson"));
+        assert_eq!(
+            actual.address().and_then(|a| a.lines()),
+            Some("1 High Street")
+        );
+        assert_eq!(actual.phone(), Some("01382 000000"));
+    }
+
+    #[test]
+    fn can_parse_submitter_with_unknown_name() {
+        let input = "0 @SUBM1@ SUBM\n1 NAME Not known\n";
+        let (_, lines) = parse_gedcom(input).unwrap();
+        let tree = GedcomTree::from(lines);
+
+        let actual = Submitter::try_from(&tree.nodes()[0]).unwrap();
+        assert_eq!(actual.name(), None);
+    }
+
+    #[test]
+    fn cannot_parse_submitter_from_other_record() {
+        let input = "0 @I1@ INDI\n1 NAME Gavin /Henderson/\n";
+        let (_, lines) = parse_gedcom(input).unwrap();
+        let tree = GedcomTree::from(lines);
+
+        let actual = Submitter::try_from(&tree.nodes()[0]);
+        assert!(actual.is_err());
+    }
+}
@@ -1,3 +1,6 @@
+#[cfg(feature = "std")]
+mod encoding;
+mod error;
 mod level;
 mod line_value;
 mod pointer;
@@ -7,14 +10,21 @@ mod terminator;
 mod util;
 mod xref_id;
 
+#[cfg(feature = "std")]
+pub use self::encoding::decode_gedcom_bytes;
+pub use self::error::ParseError;
+
 use self::level::parse_level;
-use self::line_value::parse_optional_line_value;
-use self::primitive::parse_delim;
+use self::line_value::{parse_optional_line_value, parse_optional_line_value_lenient};
+use self::primitive::{parse_delim, parse_lenient_delim};
 use self::tag::parse_tag;
 use self::terminator::parse_terminator;
 use self::util::five_tuple_to_gedcom_line;
-use self::xref_id::parse_optional_xref_id;
+use self::xref_id::{parse_optional_xref_id, parse_optional_xref_id_lenient};
 use crate::models::gedcom::GedcomLine;
+use alloc::{vec, vec::Vec};
+#[cfg(feature = "std")]
+use encoding_rs::Encoding;
 use nom::{
     character::complete::char,
     combinator::opt,
@@ -22,11 +32,78 @@ use nom::{
     sequence::{preceded, tuple},
     IResult,
 };
+#[cfg(feature = "std")]
+use std::error::Error;
 
 pub fn parse_gedcom(input: &str) -> IResult<&str, Vec<GedcomLine>> {
     preceded(parse_optional_bom, many1(parse_gedcom_line))(input)
 }
 
+#[cfg(feature = "std")]
+pub fn parse_gedcom_bytes(
+    input: &[u8],
+    encoding_override: Option<&'static Encoding>,
+) -> Result<Vec<GedcomLine>, Box<dyn Error>> {
+    let gedcom = decode_gedcom_bytes(input, encoding_override)?;
+    parse_gedcom_complete(&gedcom)
+}
+
+#[cfg(feature = "std")]
+pub fn parse_gedcom_complete(input: &str) -> Result<Vec<GedcomLine>, Box<dyn Error>> {
+    let (remaining, lines) =
+        parse_gedcom(input).map_err(|e| format!("Could not parse GEDCOM input: {}", e))?;
+
+    if !remaining.trim().is_empty() {
+        let message = format!(
+            "Could not parse GEDCOM input: {} bytes were not consumed",
+            remaining.len()
+        );
+        return Err(message.into());
+    }
+
+    Ok(lines)
+}
+
+pub fn parse_gedcom_lenient(input: &str) -> (Vec<GedcomLine>, Vec<ParseError>) {
+    let (lines, errors) = parse_gedcom_lenient_numbered(input);
+    (lines.into_iter().map(|(_, line)| line).collect(), errors)
+}
+
+pub fn parse_gedcom_lenient_numbered(input: &str) -> (Vec<(usize, GedcomLine)>, Vec<ParseError>) {
+    let mut lines = vec![];
+    let mut errors = vec![];
+    let mut line_number = 0;
+
+    let mut remaining = match parse_optional_bom(input) {
+        Ok((remaining, _)) => remaining,
+        Err(_) => input,
+    };
+
+    while !remaining.is_empty() {
+        line_number += 1;
+
+        if let Ok((rest, line)) = parse_gedcom_line_lenient(remaining) {
+            lines.push((line_number, line));
+            remaining = rest;
+            continue;
+        }
+
+        let end = remaining.find(['\r', '\n']).unwrap_or(remaining.len());
+        let (bad_line, rest) = remaining.split_at(end);
+
+        remaining = match parse_terminator(rest) {
+            Ok((rest, _)) => rest,
+            Err(_) => rest,
+        };
+
+        if !bad_line.trim().is_empty() {
+            errors.push(ParseError::new(line_number, bad_line));
+        }
+    }
+
+    (lines, errors)
+}
+
 fn parse_optional_bom(input: &str) -> IResult<&str, Option<char>> {
     opt(char('\u{feff}'))(input)
 }
@@ -43,9 +120,24 @@ fn parse_gedcom_line(input: &str) -> IResult<&str, GedcomLine> {
     .map(five_tuple_to_gedcom_line)
 }
 
+fn parse_gedcom_line_lenient(input: &str) -> IResult<&str, GedcomLine> {
+    tuple((
+        parse_level,
+        parse_lenient_delim,
+        opt(parse_optional_xref_id_lenient),
+        parse_tag,
+        opt(parse_optional_line_value_lenient),
+        parse_terminator,
+    ))(input)
+    .map(five_tuple_to_gedcom_line)
+}
+
 #[cfg(test)]
 mod tests {
-    use super::parse_gedcom_line;
+    use super::{
+        parse_gedcom_bytes, parse_gedcom_lenient, parse_gedcom_line, parse_gedcom_line_lenient,
+        ParseError,
+    };
     use crate::models::gedcom::{GedcomLine, GedcomLineTag};
 
     #[test]
@@ -884,4 +976,118 @@ mod tests {
         let actual = parse_gedcom_line(input);
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn test_parse_gedcom_line_double_space_delim_invalid() {
+        let input = "0  @I1@  INDI\r\n";
+        let actual = parse_gedcom_line(input);
+        assert!(actual.is_err());
+    }
+
+    #[test]
+    fn test_parse_gedcom_line_tab_delim_invalid() {
+        let input = "1\tNAME\tGavin /Henderson/\r\n";
+        let actual = parse_gedcom_line(input);
+        assert!(actual.is_err());
+    }
+
+    #[test]
+    fn test_parse_gedcom_line_lenient_single_space_delim() {
+        let input = "1 CTRY England\r\n";
+        let line = GedcomLine::builder()
+            .with_level(1)
+            .with_tag(GedcomLineTag::Country)
+            .with_optional_line_value(Some(String::from("England")))
+            .build()
+            .unwrap();
+        let expected = Ok(("", line));
+        let actual = parse_gedcom_line_lenient(input);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_parse_gedcom_line_lenient_double_space_delim() {
+        let input = "0  @I1@  INDI\r\n";
+        let line = GedcomLine::builder()
+            .with_level(0)
+            .with_tag(GedcomLineTag::Individual)
+            .with_optional_xref_id(Some(String::from("@I1@")))
+            .build()
+            .unwrap();
+        let expected = Ok(("", line));
+        let actual = parse_gedcom_line_lenient(input);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_parse_gedcom_line_lenient_tab_delim() {
+        let input = "1\tNAME\tGavin /Henderson/\r\n";
+        let line = GedcomLine::builder()
+            .with_level(1)
+            .with_tag(GedcomLineTag::Name)
+            .with_optional_line_value(Some(String::from("Gavin /Henderson/")))
+            .build()
+            .unwrap();
+        let expected = Ok(("", line));
+        let actual = parse_gedcom_line_lenient(input);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_parse_gedcom_lenient_skips_bad_line() {
+        let input =
+            "0 @I1@ INDI\n1 NAME Gavin /Henderson/\nthis is not gedcom\n0 @I2@ INDI\n1 SEX M";
+        let (lines, errors) = parse_gedcom_lenient(input);
+
+        let tags: Vec<&GedcomLineTag> = lines.iter().map(|l| l.tag()).collect();
+        assert_eq!(
+            tags,
+            vec![
+                &GedcomLineTag::Individual,
+                &GedcomLineTag::Name,
+                &GedcomLineTag::Individual,
+                &GedcomLineTag::Sex,
+            ]
+        );
+        assert_eq!(errors, vec![ParseError::new(3, "this is not gedcom")]);
+    }
+
+    #[test]
+    fn test_parse_gedcom_bytes_windows_1252() {
+        let input = b"0 HEAD\n0 @I1@ INDI\n1 NAME Ren\xe9 /M\xfcller/\n0 TRLR\n";
+        let lines = parse_gedcom_bytes(input, None).unwrap();
+
+        assert_eq!(lines[2].tag(), &GedcomLineTag::Name);
+        assert_eq!(lines[2].line_value().as_deref(), Some("René /Müller/"));
+    }
+
+    #[test]
+    fn test_parse_gedcom_line_trailing_delim_has_no_line_value() {
+        let line = GedcomLine::builder()
+            .with_level(1)
+            .with_tag(GedcomLineTag::Note)
+            .build()
+            .unwrap();
+
+        let actual = parse_gedcom_line("1 NOTE \r\n");
+        assert_eq!(actual, Ok(("", line)));
+
+        let line = GedcomLine::builder()
+            .with_level(1)
+            .with_tag(GedcomLineTag::Note)
+            .build()
+            .unwrap();
+
+        let actual = parse_gedcom_line("1 NOTE\r\n");
+        assert_eq!(actual, Ok(("", line)));
+
+        let line = GedcomLine::builder()
+            .with_level(1)
+            .with_tag(GedcomLineTag::Note)
+            .build()
+            .unwrap();
+
+        let actual = parse_gedcom_line_lenient("1\tNOTE \t\r\n");
+        assert_eq!(actual, Ok(("", line)));
+    }
 }
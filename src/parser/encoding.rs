@@ -0,0 +1,109 @@
+use encoding_rs::{Encoding, UTF_16LE, UTF_8, WINDOWS_1252};
+use std::{error::Error, str};
+
+const CHAR_TAG: &str = "CHAR";
+
+pub fn decode_gedcom_bytes(
+    input: &[u8],
+    encoding_override: Option<&'static Encoding>,
+) -> Result<String, Box<dyn Error>> {
+    if let Some(encoding) = encoding_override {
+        return decode(input, encoding);
+    }
+
+    if let Some((encoding, _)) = Encoding::for_bom(input) {
+        return decode(input, encoding);
+    }
+
+    match declared_encoding(input) {
+        Some(Ok(encoding)) => decode(input, encoding),
+        Some(Err(declared)) => match str::from_utf8(input) {
+            Ok(gedcom) => Ok(gedcom.to_owned()),
+            Err(_) => Err(format!("Unsupported GEDCOM character set {}", declared).into()),
+        },
+        None => match str::from_utf8(input) {
+            Ok(gedcom) => Ok(gedcom.to_owned()),
+            Err(_) => decode(input, WINDOWS_1252),
+        },
+    }
+}
+
+fn decode(input: &[u8], encoding: &'static Encoding) -> Result<String, Box<dyn Error>> {
+    let (gedcom, _, had_errors) = encoding.decode(input);
+
+    if had_errors {
+        return Err(format!("GEDCOM input is not valid {}", encoding.name()).into());
+    }
+
+    Ok(gedcom.into_owned())
+}
+
+fn declared_encoding(input: &[u8]) -> Option<Result<&'static Encoding, String>> {
+    let declared = input
+        .split(|b| *b == b'\n' || *b == b'\r')
+        .map(|line| String::from_utf8_lossy(line))
+        .find_map(|line| {
+            let mut parts = line.split_whitespace();
+            match (parts.next(), parts.next(), parts.next()) {
+                (Some("1"), Some(tag), Some(value)) if tag.eq_ignore_ascii_case(CHAR_TAG) => {
+                    Some(value.to_uppercase())
+                }
+                _ => None,
+            }
+        })?;
+
+    let encoding = match declared.as_str() {
+        "ANSI" | "CP1252" | "WINDOWS-1252" => Some(WINDOWS_1252),
+        "ASCII" | "UTF-8" | "UTF8" => Some(UTF_8),
+        "UNICODE" => Some(UTF_16LE),
+        other => Encoding::for_label(other.as_bytes()),
+    };
+
+    Some(encoding.ok_or(declared))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::decode_gedcom_bytes;
+    use encoding_rs::WINDOWS_1252;
+
+    #[test]
+    fn test_decode_utf8() {
+        let input = "0 HEAD\n1 CHAR UTF-8\n0 @I1@ INDI\n1 NAME René /Müller/\n".as_bytes();
+        let actual = decode_gedcom_bytes(input, None).unwrap();
+        assert!(actual.contains("René /Müller/"));
+    }
+
+    #[test]
+    fn test_decode_undeclared_windows_1252() {
+        let input = b"0 HEAD\n0 @I1@ INDI\n1 NAME Ren\xe9 /M\xfcller/\n0 TRLR\n";
+        let actual = decode_gedcom_bytes(input, None).unwrap();
+        assert!(actual.contains("René /Müller/"));
+    }
+
+    #[test]
+    fn test_decode_declared_ansi() {
+        let input = b"0 HEAD\n1 CHAR ANSI\n0 @I1@ INDI\n1 NAME Ren\xe9\n";
+        let actual = decode_gedcom_bytes(input, None).unwrap();
+        assert!(actual.contains("René"));
+    }
+
+    #[test]
+    fn test_decode_encoding_override() {
+        let input = b"0 HEAD\n1 CHAR UTF-8\n0 @I1@ INDI\n1 NAME Ren\xe9\n";
+        assert!(decode_gedcom_bytes(input, None).is_err());
+
+        let actual = decode_gedcom_bytes(input, Some(WINDOWS_1252)).unwrap();
+        assert!(actual.contains("René"));
+    }
+
+    #[test]
+    fn test_decode_unsupported_declared_encoding() {
+        let input = b"0 HEAD\n1 CHAR ANSEL\n0 @I1@ INDI\n1 NAME Ren\xe2e\n";
+        let actual = decode_gedcom_bytes(input, None).map_err(|e| e.to_string());
+        assert_eq!(
+            actual,
+            Err(String::from("Unsupported GEDCOM character set ANSEL"))
+        );
+    }
+}
@@ -0,0 +1,52 @@
+use alloc::{borrow::ToOwned, string::String};
+use core::fmt;
+#[cfg(feature = "std")]
+use std::error::Error;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    line: String,
+    line_number: usize,
+}
+
+impl ParseError {
+    pub fn new(line_number: usize, line: &str) -> Self {
+        Self {
+            line: line.to_owned(),
+            line_number,
+        }
+    }
+
+    pub fn line(&self) -> &str {
+        &self.line
+    }
+
+    pub fn line_number(&self) -> usize {
+        self.line_number
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Could not parse line {}: {}",
+            self.line_number, self.line
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::ParseError;
+
+    #[test]
+    fn test_display_parse_error() {
+        let actual = ParseError::new(3, "garbage").to_string();
+        let expected = "Could not parse line 3: garbage";
+        assert_eq!(actual, expected);
+    }
+}
@@ -1,17 +1,26 @@
 use super::{
     pointer::parse_pointer,
-    primitive::{parse_anychar, parse_at, parse_delim, parse_hash, parse_nonat},
+    primitive::{
+        parse_anychar, parse_at, parse_delim, parse_hash, parse_lenient_delim, parse_nonat,
+    },
+    terminator::parse_terminator,
     util::{five_tuple_to_string, vec_to_string},
 };
+use alloc::string::String;
 use nom::{
     branch::alt,
+    combinator::{opt, peek},
     multi::many1,
-    sequence::{preceded, tuple},
+    sequence::{preceded, terminated, tuple},
     IResult,
 };
 
 pub fn parse_optional_line_value(input: &str) -> IResult<&str, String> {
-    preceded(parse_delim, parse_line_value)(input)
+    preceded(parse_delim, parse_line_value_or_empty)(input)
+}
+
+pub fn parse_optional_line_value_lenient(input: &str) -> IResult<&str, String> {
+    preceded(parse_lenient_delim, parse_line_value_or_empty)(input)
 }
 
 fn parse_escape(input: &str) -> IResult<&str, String> {
@@ -34,16 +43,26 @@ fn parse_line_item(input: &str) -> IResult<&str, String> {
 }
 
 fn parse_line_item_inner(input: &str) -> IResult<&str, String> {
-    alt((parse_anychar, parse_escape))(input)
+    alt((parse_anychar, parse_escape, parse_at))(input)
 }
 
 fn parse_line_value(input: &str) -> IResult<&str, String> {
-    alt((parse_line_item, parse_pointer))(input)
+    alt((parse_pointer_value, parse_line_item))(input)
+}
+
+fn parse_pointer_value(input: &str) -> IResult<&str, String> {
+    terminated(parse_pointer, peek(parse_terminator))(input)
+}
+
+fn parse_line_value_or_empty(input: &str) -> IResult<&str, String> {
+    opt(parse_line_value)(input).map(|(input, value)| (input, value.unwrap_or_default()))
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{parse_escape, parse_line_item, parse_optional_line_value};
+    use super::{
+        parse_escape, parse_line_item, parse_optional_line_value, parse_optional_line_value_lenient,
+    };
     use nom::{error::ErrorKind, Err};
 
     #[test]
@@ -113,7 +132,7 @@ mod tests {
     #[test]
     fn test_parse_line_item_valid_multiple_non_escapes() {
         let input = "NON_ESCAPES_WITH_DOUBLE_AT_@@";
-        let expected = Ok(("", String::from("NON_ESCAPES_WITH_DOUBLE_AT_@@")));
+        let expected = Ok(("", String::from("NON_ESCAPES_WITH_DOUBLE_AT_@")));
         let actual = parse_line_item(input);
         assert_eq!(actual, expected);
     }
@@ -157,4 +176,60 @@ mod tests {
         let actual = parse_optional_line_value(input);
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn test_parse_optional_line_value_lenient_multiple_spaces() {
+        let input = "   FOO";
+        let expected = Ok(("", String::from("FOO")));
+        let actual = parse_optional_line_value_lenient(input);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_parse_optional_line_value_lenient_tab() {
+        let input = "\tFOO";
+        let expected = Ok(("", String::from("FOO")));
+        let actual = parse_optional_line_value_lenient(input);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_parse_optional_line_value_collapses_double_at() {
+        let input = " me@@home";
+        let expected = Ok(("", String::from("me@home")));
+        let actual = parse_optional_line_value(input);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_parse_optional_line_value_keeps_pointer() {
+        let input = " @I1@";
+        let expected = Ok(("", String::from("@I1@")));
+        let actual = parse_optional_line_value(input);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_parse_optional_line_value_pointer_with_text_is_text() {
+        let input = " @I1@ extra\r\n";
+        let expected = Ok(("\r\n", String::from("@I1@ extra")));
+        let actual = parse_optional_line_value(input);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_parse_optional_line_value_single_at_is_text() {
+        let input = " me@work\r\n";
+        let expected = Ok(("\r\n", String::from("me@work")));
+        let actual = parse_optional_line_value(input);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_parse_optional_line_value_trailing_delim() {
+        let input = " \r\n";
+        let expected = Ok(("\r\n", String::new()));
+        let actual = parse_optional_line_value(input);
+        assert_eq!(actual, expected);
+    }
 }
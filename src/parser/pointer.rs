@@ -2,6 +2,7 @@ use super::{
     primitive::{parse_alphanum, parse_at, parse_nonat},
     util::{four_tuple_to_string, vec_to_string},
 };
+use alloc::string::String;
 use nom::{multi::many0, sequence::tuple, IResult};
 
 pub fn parse_pointer(input: &str) -> IResult<&str, String> {
@@ -1,18 +1,10 @@
 use super::{primitive::parse_alphanum, util::vec_to_string};
 use crate::models::gedcom::GedcomLineTag;
-use lazy_static::lazy_static;
 use nom::{
     error::{make_error, ErrorKind},
     multi::many1,
     Err, IResult,
 };
-use regex::Regex;
-
-lazy_static! {
-    static ref VALID_TAG: Regex = Regex::new(
-        r#"(?i:ABBR|ADDR|ADR1|ADR2|ADOP|AFN|AGE|AGNC|ALIA|ANCE|ANCI|ANUL|ASSO|AUTH|BAPL|BAPM|BARM|BASM|BIRT|BLES|BURI|CALN|CAST|CAUS|CENS|CHAN|CHAR|CHIL|CHR|CHRA|CITY|CONC|CONF|CONL|CONT|COPR|CORP|CREM|CTRY|DATA|DATE|DEAT|DESC|DESI|DEST|DIV|DIVF|DSCR|EDUC|EMAI|EMIG|ENDL|ENGA|EVEN|FACT|FAM|FAMC|FAMF|FAMS|FAX|FCOM|FILE|FORM|FONE|GEDC|GIVN|GRAD|HEAD|HUSB|IDNO|IMMI|INDI|LANG|LATI|LONG|MAP|MARB|MARC|MARL|MARR|MARS|MEDI|NAME|NATI|NATU|NCHI|NICK|NMR|NOTE|NPFX|NSFX|OBJE|OCCU|ORDI|ORDN|PAGE|PEDI|PHON|PLAC|POST|PROB|PROP|PUBL|QUAY|REFN|RELA|RELI|REPO|RESI|RESN|RETI|RFN|RIN|ROLE|ROMN|SEX|SLGC|SLGS|SOUR|SPFX|SSN|STAE|STAT|SUBM|SUBN|SURN|TEMP|TEXT|TIME|TITL|TRLR|TYPE|VERS|WIFE|WILL|WWW|_[A-Za-z0-9_]+)"#
-    ).unwrap();
-}
 
 pub fn parse_tag(input: &str) -> IResult<&str, GedcomLineTag> {
     match many1(parse_alphanum)(input).map(vec_to_string) {
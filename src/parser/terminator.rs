@@ -2,7 +2,13 @@ use super::{
     primitive::{parse_carriage_return, parse_line_feed},
     util::tuple_to_string,
 };
-use nom::{branch::alt, sequence::pair, IResult};
+use alloc::string::String;
+use nom::{
+    branch::alt,
+    error::{make_error, ErrorKind},
+    sequence::pair,
+    Err, IResult,
+};
 
 pub fn parse_terminator(input: &str) -> IResult<&str, String> {
     alt((
@@ -10,9 +16,18 @@ pub fn parse_terminator(input: &str) -> IResult<&str, String> {
         parse_lfcr,
         parse_carriage_return,
         parse_line_feed,
+        parse_end_of_input,
     ))(input)
 }
 
+fn parse_end_of_input(input: &str) -> IResult<&str, String> {
+    if input.is_empty() {
+        Ok((input, String::new()))
+    } else {
+        Err(Err::Error(make_error(input, ErrorKind::Eof)))
+    }
+}
+
 fn parse_crlf(input: &str) -> IResult<&str, String> {
     pair(parse_carriage_return, parse_line_feed)(input).map(tuple_to_string)
 }
@@ -20,3 +35,30 @@ fn parse_crlf(input: &str) -> IResult<&str, String> {
 fn parse_lfcr(input: &str) -> IResult<&str, String> {
     pair(parse_line_feed, parse_carriage_return)(input).map(tuple_to_string)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::parse_terminator;
+    use nom::{error::ErrorKind, Err};
+
+    #[test]
+    fn test_parse_terminator_crlf() {
+        let expected = Ok(("0 TRLR", String::from("\r\n")));
+        let actual = parse_terminator("\r\n0 TRLR");
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_parse_terminator_end_of_input() {
+        let expected = Ok(("", String::new()));
+        let actual = parse_terminator("");
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_parse_terminator_invalid() {
+        let expected = Err(Err::Error(("garbage", ErrorKind::Eof)));
+        let actual = parse_terminator("garbage");
+        assert_eq!(actual, expected);
+    }
+}
@@ -1,5 +1,10 @@
 use crate::models::gedcom::{GedcomLine, GedcomLineTag};
-use std::iter::FromIterator;
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::iter::FromIterator;
 
 pub fn five_tuple_to_gedcom_line(
     (input, (level, _delim, optional_xref_id, tag, optional_line_value, _terminator)): (
@@ -16,7 +21,7 @@ pub fn five_tuple_to_gedcom_line(
 ) -> (&str, GedcomLine) {
     let line = GedcomLine::builder()
         .with_level(level)
-        .with_optional_line_value(optional_line_value)
+        .with_optional_line_value(optional_line_value.filter(|v| !v.is_empty()))
         .with_tag(tag)
         .with_optional_xref_id(optional_xref_id)
         .build()
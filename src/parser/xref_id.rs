@@ -1,10 +1,18 @@
-use super::{pointer::parse_pointer, primitive::parse_delim};
+use super::{
+    pointer::parse_pointer,
+    primitive::{parse_delim, parse_lenient_delim},
+};
+use alloc::string::String;
 use nom::{sequence::terminated, IResult};
 
 pub fn parse_optional_xref_id(input: &str) -> IResult<&str, String> {
     terminated(parse_xref_id, parse_delim)(input)
 }
 
+pub fn parse_optional_xref_id_lenient(input: &str) -> IResult<&str, String> {
+    terminated(parse_xref_id, parse_lenient_delim)(input)
+}
+
 fn parse_xref_id(input: &str) -> IResult<&str, String> {
     parse_pointer(input)
 }
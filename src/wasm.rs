@@ -0,0 +1,24 @@
+use crate::convert::gedcom_to_relation_json;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub fn convert(input: &str) -> Result<String, JsValue> {
+    gedcom_to_relation_json(input).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::convert;
+    use crate::{convert::gedcom_to_relation_json, load_fixture};
+
+    #[test]
+    fn convert_matches_relation_json() {
+        let input = load_fixture("sibling");
+
+        let actual = convert(&input).ok();
+        let expected = gedcom_to_relation_json(&input).ok();
+
+        assert!(actual.is_some());
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,283 @@
+use crate::models::gedcom::{GedcomLineTag, GedcomTree, GedcomTreeNode, Xref};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+const TERMINATOR: &str = "\r\n";
+
+lazy_static! {
+    static ref ESCAPE: Regex = Regex::new(r#"@#[^@]+@"#).unwrap();
+}
+
+pub fn write_gedcom(tree: &GedcomTree) -> String {
+    let mut output = String::new();
+    let (headers, records): (Vec<&GedcomTreeNode>, Vec<&GedcomTreeNode>) = tree
+        .nodes()
+        .iter()
+        .filter(|node| node.tag() != &GedcomLineTag::Trailer)
+        .partition(|node| node.tag() == &GedcomLineTag::Header);
+
+    for node in headers.into_iter().chain(records) {
+        write_node(node, 0, &mut output);
+    }
+
+    output.push_str("0 ");
+    output.push_str(GedcomLineTag::Trailer.as_str());
+    output.push_str(TERMINATOR);
+    output
+}
+
+pub fn escape_line_value(value: &str) -> String {
+    let mut escaped = String::new();
+    let mut last = 0;
+
+    for escape in ESCAPE.find_iter(value) {
+        escaped.push_str(&value[last..escape.start()].replace('@', "@@"));
+        escaped.push_str(escape.as_str());
+        last = escape.end();
+    }
+
+    escaped.push_str(&value[last..].replace('@', "@@"));
+    escaped
+}
+
+pub(crate) fn is_pointer_value(value: &str) -> bool {
+    !value.contains('\n') && value.parse::<Xref>().is_ok()
+}
+
+fn write_node(node: &GedcomTreeNode, level: u8, output: &mut String) {
+    output.push_str(&level.to_string());
+
+    if let Some(xref_id) = node.xref_id() {
+        output.push(' ');
+        output.push_str(xref_id);
+    }
+
+    output.push(' ');
+    output.push_str(node.tag().as_str());
+
+    let mut continued = vec![];
+
+    if let Some(line_value) = node.line_value() {
+        if is_pointer_value(line_value) {
+            output.push(' ');
+            output.push_str(line_value);
+        } else {
+            let mut segments = line_value.split('\n');
+            write_value(segments.next(), output);
+            continued.extend(segments);
+        }
+    }
+
+    output.push_str(TERMINATOR);
+
+    for segment in continued.into_iter() {
+        output.push_str(&(level + 1).to_string());
+        output.push(' ');
+        output.push_str(GedcomLineTag::Continued.as_str());
+        write_value(Some(segment), output);
+        output.push_str(TERMINATOR);
+    }
+
+    for child in node.children().iter() {
+        write_node(child, level + 1, output);
+    }
+}
+
+fn write_value(value: Option<&str>, output: &mut String) {
+    if let Some(value) = value.filter(|v| !v.is_empty()) {
+        output.push(' ');
+        output.push_str(&escape_line_value(value));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{escape_line_value, is_pointer_value, write_gedcom};
+    use crate::{
+        models::gedcom::{
+            GedcomLine, GedcomLineTag, GedcomTree, GedcomTreeNode, GedcomTreeNodeBuilder,
+            WarningKind,
+        },
+        parser::parse_gedcom,
+    };
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_escape_line_value() {
+        assert_eq!(escape_line_value("me@home"), "me@@home");
+        assert_eq!(
+            escape_line_value("@#DJULIAN@ 1 JAN 1700 me@home"),
+            "@#DJULIAN@ 1 JAN 1700 me@@home"
+        );
+    }
+
+    #[test]
+    fn test_write_gedcom_matches_line_strings() {
+        for value in ["@I1@", "me@home", "@I1@ extra", "@#DJULIAN@ 1 JAN 1700"].iter() {
+            let line = GedcomLine::builder()
+                .with_level(0)
+                .with_tag(GedcomLineTag::Note)
+                .with_optional_line_value(Some(String::from(*value)))
+                .build()
+                .unwrap();
+            let tree = GedcomTree::from(vec![line.clone()]);
+
+            assert!(write_gedcom(&tree).starts_with(&line.to_line_string()));
+        }
+
+        assert!(is_pointer_value("@I1@"));
+        assert!(!is_pointer_value("@I1\n2@"));
+    }
+
+    #[test]
+    fn test_write_gedcom_re_expands_double_at() {
+        let input = "0 @I1@ INDI\r\n1 EMAI me@@home\r\n1 FAMC @F1@\r\n0 TRLR\r\n";
+        let (_, lines) = parse_gedcom(input).unwrap();
+        let tree = GedcomTree::from(lines);
+
+        let email = &tree.nodes()[0].children()[0];
+        assert_eq!(email.line_value().as_deref(), Some("me@home"));
+        assert_eq!(email.pointer(), None);
+
+        let family = &tree.nodes()[0].children()[1];
+        assert!(family.pointer().is_some());
+
+        assert_eq!(write_gedcom(&tree), input);
+    }
+
+    #[test]
+    fn test_write_gedcom_orders_header_records_and_trailer() {
+        let input = "0 @I1@ INDI\r\n1 NAME Jane /Reed/\r\n0 HEAD\r\n1 CHAR UTF-8\r\n0 @F1@ FAM\r\n1 WIFE @I1@\r\n";
+        let (_, lines) = parse_gedcom(input).unwrap();
+        let tree = GedcomTree::from(lines);
+
+        let actual = write_gedcom(&tree);
+
+        assert!(actual.ends_with("0 TRLR\r\n"));
+        assert_eq!(
+            actual,
+            "0 HEAD\r\n1 CHAR UTF-8\r\n0 @I1@ INDI\r\n1 NAME Jane /Reed/\r\n0 @F1@ FAM\r\n1 WIFE @I1@\r\n0 TRLR\r\n"
+        );
+    }
+
+    #[test]
+    fn test_write_gedcom_recomputes_levels_from_depth() {
+        let line = |level, tag, line_value: Option<&str>| {
+            GedcomLine::builder()
+                .with_level(level)
+                .with_tag(tag)
+                .with_optional_line_value(line_value.map(String::from))
+                .build()
+                .unwrap()
+        };
+
+        let given_names =
+            GedcomTreeNodeBuilder::from(line(5, GedcomLineTag::GivenName, Some("Jane"))).build();
+        let name = GedcomTreeNodeBuilder::from(line(2, GedcomLineTag::Name, Some("Jane /Reed/")))
+            .with_children(vec![given_names])
+            .build();
+        let individual = GedcomTreeNodeBuilder::from(line(1, GedcomLineTag::Individual, None))
+            .with_children(vec![name])
+            .build();
+        let tree = GedcomTree::from(vec![individual]);
+
+        assert!(tree
+            .validate()
+            .iter()
+            .any(|w| w.kind() == WarningKind::BadLevel));
+
+        let actual = write_gedcom(&tree);
+        assert_eq!(
+            actual,
+            "0 INDI\r\n1 NAME Jane /Reed/\r\n2 GIVN Jane\r\n0 TRLR\r\n"
+        );
+
+        let (_, lines) = parse_gedcom(&actual).unwrap();
+        assert!(GedcomTree::from(lines)
+            .validate()
+            .iter()
+            .all(|w| w.kind() != WarningKind::BadLevel));
+    }
+
+    #[test]
+    fn test_write_gedcom_splits_folded_value_into_cont_lines() {
+        let input = "0 @N1@ NOTE First line\r\n1 CONT Second me@@home\r\n1 CONT\r\n1 CONT Fourth\r\n0 TRLR\r\n";
+        let (_, lines) = parse_gedcom(input).unwrap();
+        let tree = GedcomTree::from(lines);
+
+        assert_eq!(
+            tree.nodes()[0].line_value().as_deref(),
+            Some("First line\nSecond me@home\n\nFourth")
+        );
+        assert_eq!(write_gedcom(&tree), input);
+    }
+
+    fn nested_lines(lines: Vec<GedcomLine>) -> Vec<GedcomLine> {
+        let mut previous_level = None;
+
+        lines
+            .into_iter()
+            .map(|line| {
+                let level = match previous_level {
+                    None => 0,
+                    Some(previous) => line.level().min(previous + 1),
+                };
+                let xref_id = line.xref_id().clone().filter(|_| level == 0);
+                let tag = match line.tag() {
+                    GedcomLineTag::Header | GedcomLineTag::Trailer if level == 0 => {
+                        GedcomLineTag::Note
+                    }
+                    tag => tag.clone(),
+                };
+                previous_level = Some(level);
+
+                GedcomLine::builder()
+                    .with_level(level)
+                    .with_optional_line_value(line.line_value().clone())
+                    .with_tag(tag)
+                    .with_optional_xref_id(xref_id)
+                    .build()
+                    .unwrap()
+            })
+            .collect()
+    }
+
+    fn flatten_nodes(
+        nodes: &[GedcomTreeNode],
+        depth: u8,
+        output: &mut Vec<(u8, Option<String>, GedcomLineTag, Option<String>)>,
+    ) {
+        for node in nodes.iter() {
+            output.push((
+                depth,
+                node.xref_id().clone(),
+                node.tag().clone(),
+                node.line_value().clone(),
+            ));
+            flatten_nodes(node.children(), depth + 1, output);
+        }
+    }
+
+    fn flatten_tree(tree: &GedcomTree) -> Vec<(u8, Option<String>, GedcomLineTag, Option<String>)> {
+        let mut output = vec![];
+        flatten_nodes(tree.nodes(), 0, &mut output);
+        output
+    }
+
+    proptest! {
+        #[test]
+        fn test_write_gedcom_round_trips(lines in prop::collection::vec(any::<GedcomLine>(), 1..40)) {
+            let mut lines = nested_lines(lines);
+            let output = write_gedcom(&GedcomTree::from(lines.clone()));
+
+            lines.push(GedcomLine::builder().with_level(0).with_tag(GedcomLineTag::Trailer).build().unwrap());
+
+            let (remaining, actual) = parse_gedcom(&output).unwrap();
+            prop_assert_eq!(remaining, "");
+            prop_assert_eq!(
+                flatten_tree(&GedcomTree::from(actual)),
+                flatten_tree(&GedcomTree::from(lines))
+            );
+        }
+    }
+}
@@ -0,0 +1,30 @@
+#![cfg(feature = "async")]
+
+use gedcom::{convert_directory, gedcom_to_relation_json};
+use std::{fs, path::Path};
+
+#[tokio::test]
+async fn convert_directory_test() {
+    let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+    let directory = tempfile::tempdir().unwrap();
+
+    for name in ["one_node.ged", "three_node.ged"].iter() {
+        fs::copy(fixtures.join(name), directory.path().join(name)).unwrap();
+    }
+
+    let actual = convert_directory(directory.path()).await.unwrap();
+
+    let paths: Vec<_> = actual.iter().map(|(path, _)| path.clone()).collect();
+    assert_eq!(
+        paths,
+        vec![
+            directory.path().join("one_node.ged"),
+            directory.path().join("three_node.ged"),
+        ]
+    );
+
+    for (path, json) in actual.iter() {
+        let input = fs::read_to_string(path).unwrap();
+        assert_eq!(json, &gedcom_to_relation_json(&input).unwrap());
+    }
+}
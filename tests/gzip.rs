@@ -0,0 +1,21 @@
+#![cfg(feature = "gzip")]
+
+use flate2::{write::GzEncoder, Compression};
+use gedcom::{decompress_gzip, gedcom_to_relation_json};
+use std::{fs, io::Write, path::Path};
+
+#[test]
+fn gzip_gedcom_test() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/three_node.ged");
+    let input = fs::read_to_string(path).unwrap();
+
+    let mut encoder = GzEncoder::new(vec![], Compression::default());
+    encoder.write_all(input.as_bytes()).unwrap();
+    let compressed = encoder.finish().unwrap();
+
+    let decompressed = decompress_gzip(&compressed).unwrap();
+
+    let expected = gedcom_to_relation_json(&input).unwrap();
+    let actual = gedcom_to_relation_json(&decompressed).unwrap();
+    assert_eq!(actual, expected);
+}
@@ -0,0 +1,51 @@
+#![cfg(feature = "std")]
+
+use std::{path::Path, process::Command};
+
+fn fixture(name: &str) -> String {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(name)
+        .display()
+        .to_string()
+}
+
+#[test]
+fn validate_reports_problems_with_line_numbers() {
+    let path = fixture("broken.ged");
+    let output = Command::new(env!("CARGO_BIN_EXE_gedcom"))
+        .args(["--validate", "--file", path.as_str()])
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(1));
+
+    let expected = [
+        format!("{}: Line 5: Pointer @F9@ does not match any record", path),
+        format!(
+            "{}: Line 6: Duplicate xref @I1@, first defined on line 3",
+            path
+        ),
+        format!(
+            "{}: Line 8: Line could not be parsed: this line is not gedcom",
+            path
+        ),
+        format!("{}: Line 9: Line has level 3, expected 2", path),
+        format!("{}: Line 9: Missing trailer", path),
+    ];
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(actual.lines().collect::<Vec<_>>(), expected);
+
+    assert!(!Path::new(&path).with_extension("json").exists());
+}
+
+#[test]
+fn validate_succeeds_for_valid_file() {
+    let output = Command::new(env!("CARGO_BIN_EXE_gedcom"))
+        .args(["--validate", "--file", fixture("three_node.ged").as_str()])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+}